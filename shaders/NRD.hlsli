@@ -0,0 +1,373 @@
+// Packing functions matching the NRD binary linked by nrd-sys.
+//
+// NRD_NORMAL_ENCODING and NRD_ROUGHNESS_ENCODING must be defined before including this file, use
+// `nrd_sys::shader::ShaderLanguage::Hlsl.include( nrd_sys::Instance::library_desc( ) )` to get a
+// version with the encodings of the linked library.
+
+#ifndef NRD_HLSLI
+#define NRD_HLSLI
+
+#define NRD_NORMAL_ENCODING_RGBA8_UNORM                         0
+#define NRD_NORMAL_ENCODING_RGBA8_SNORM                         1
+#define NRD_NORMAL_ENCODING_R10G10B10A2_UNORM                   2 // supports material ID bits
+#define NRD_NORMAL_ENCODING_RGBA16_UNORM                        3
+#define NRD_NORMAL_ENCODING_RGBA16_SNORM                        4 // also can be used with FP formats
+
+#define NRD_ROUGHNESS_ENCODING_SQ_LINEAR                        0 // linearRoughness * linearRoughness
+#define NRD_ROUGHNESS_ENCODING_LINEAR                           1 // linearRoughness
+#define NRD_ROUGHNESS_ENCODING_SQRT_LINEAR                      2 // sqrt( linearRoughness )
+
+#ifndef NRD_NORMAL_ENCODING
+    #error "NRD_NORMAL_ENCODING is not defined"
+#endif
+
+#ifndef NRD_ROUGHNESS_ENCODING
+    #error "NRD_ROUGHNESS_ENCODING is not defined"
+#endif
+
+#define NRD_FP16_MIN                                            1e-7 // min allowed hitDist (0 = no data)
+#define NRD_FP16_MAX                                            65504.0
+#define NRD_EPS                                                 1e-6
+#define NRD_FP16_VIEWZ_SCALE                                    0.125 // z in FP16 textures = viewZ * NRD_FP16_VIEWZ_SCALE
+#define SIGMA_MIN_DISTANCE                                      0.0001 // not 0, because it means "NoL < 0, stop processing"
+
+//=================================================================================================================================
+// PRIVATE
+//=================================================================================================================================
+
+float2 _NRD_EncodeUnitVector( float3 v, const bool bSigned )
+{
+    v.xy /= dot( abs( v ), 1.0 );
+
+    float2 octWrap = ( 1.0 - abs( v.yx ) ) * ( step( 0.0, v.xy ) * 2.0 - 1.0 );
+    v.xy = v.z >= 0.0 ? v.xy : octWrap;
+
+    return bSigned ? v.xy : v.xy * 0.5 + 0.5;
+}
+
+float3 _NRD_DecodeUnitVector( float2 p, const bool bSigned )
+{
+    p = bSigned ? p : ( p * 2.0 - 1.0 );
+
+    // https://twitter.com/Stubbesaurus/status/937994790553227264
+    float3 n = float3( p.xy, 1.0 - abs( p.x ) - abs( p.y ) );
+    float t = saturate( -n.z );
+    n.xy -= t * ( step( 0.0, n.xy ) * 2.0 - 1.0 );
+
+    return n;
+}
+
+float3 _NRD_SafeNormalize( float3 v )
+{
+    return v * rsqrt( max( dot( v, v ), NRD_EPS ) );
+}
+
+float3 _NRD_LinearToYCoCg( float3 color )
+{
+    float Y = dot( color, float3( 0.25, 0.5, 0.25 ) );
+    float Co = dot( color, float3( 0.5, 0.0, -0.5 ) );
+    float Cg = dot( color, float3( -0.25, 0.5, -0.25 ) );
+
+    return float3( Y, Co, Cg );
+}
+
+float3 _NRD_YCoCgToLinear( float3 color )
+{
+    float t = color.x - color.z;
+
+    float3 r;
+    r.y = color.x + color.z;
+    r.x = t + color.y;
+    r.z = t - color.y;
+
+    return max( r, 0.0 );
+}
+
+float3 _NRD_YCoCgToLinear_Corrected( float Y, float Y0, float2 CoCg )
+{
+    Y = max( Y, 0.0 );
+    CoCg *= ( Y + NRD_EPS ) / ( Y0 + NRD_EPS );
+
+    return _NRD_YCoCgToLinear( float3( Y, CoCg ) );
+}
+
+float _NRD_PackViewZ( float z )
+{
+    return clamp( z * NRD_FP16_VIEWZ_SCALE, -NRD_FP16_MAX, NRD_FP16_MAX );
+}
+
+float3 _NRD_SanitizeRadiance( float3 radiance )
+{
+    return ( any( isnan( radiance ) ) || any( isinf( radiance ) ) ) ? 0 : clamp( radiance, 0, NRD_FP16_MAX );
+}
+
+float _REBLUR_GetHitDistanceNormalization( float viewZ, float4 hitDistParams, float roughness = 1.0 )
+{
+    return ( hitDistParams.x + abs( viewZ ) * hitDistParams.y ) * lerp( 1.0, hitDistParams.z, saturate( exp2( hitDistParams.w * roughness * roughness ) ) );
+}
+
+//=================================================================================================================================
+// FRONT-END PACKING
+//=================================================================================================================================
+
+float4 NRD_FrontEnd_PackNormalAndRoughness( float3 N, float linearRoughness, float materialID = 0 )
+{
+    float4 p;
+
+    #if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQRT_LINEAR )
+        linearRoughness = sqrt( saturate( linearRoughness ) );
+    #elif( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQ_LINEAR )
+        linearRoughness *= linearRoughness;
+    #endif
+
+    #if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_R10G10B10A2_UNORM )
+        p.xy = _NRD_EncodeUnitVector( N, false );
+        p.z = linearRoughness;
+        p.w = saturate( materialID / 3.0 );
+    #else
+        // Best fit
+        N /= max( abs( N.x ), max( abs( N.y ), abs( N.z ) ) );
+
+        #if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA8_UNORM || NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA16_UNORM )
+            N = N * 0.5 + 0.5;
+        #endif
+
+        p.xyz = N;
+        p.w = linearRoughness;
+    #endif
+
+    return p;
+}
+
+// Returns normal and linear roughness, "materialID" is in [0; 3]
+float4 NRD_FrontEnd_UnpackNormalAndRoughness( float4 p, out float materialID )
+{
+    float4 r;
+    #if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_R10G10B10A2_UNORM )
+        r.xyz = _NRD_DecodeUnitVector( p.xy, false );
+        r.w = p.z;
+
+        materialID = p.w * 3.0;
+    #else
+        #if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA8_UNORM || NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA16_UNORM )
+            p.xyz = p.xyz * 2.0 - 1.0;
+        #endif
+
+        r.xyz = p.xyz;
+        r.w = p.w;
+
+        materialID = 0;
+    #endif
+
+    r.xyz = _NRD_SafeNormalize( r.xyz );
+
+    #if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQRT_LINEAR )
+        r.w *= r.w;
+    #elif( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQ_LINEAR )
+        r.w = sqrt( saturate( r.w ) );
+    #endif
+
+    return r;
+}
+
+float4 NRD_FrontEnd_UnpackNormalAndRoughness( float4 p )
+{
+    float unused;
+
+    return NRD_FrontEnd_UnpackNormalAndRoughness( p, unused );
+}
+
+// "hitDistParams" is "HitDistanceParameters" packed as ( A, B, C, D )
+float REBLUR_FrontEnd_GetNormHitDist( float hitDist, float viewZ, float4 hitDistParams, float roughness = 1.0 )
+{
+    float f = _REBLUR_GetHitDistanceNormalization( viewZ, hitDistParams, roughness );
+
+    return saturate( hitDist / f );
+}
+
+float REBLUR_GetHitDist( float normHitDist, float viewZ, float4 hitDistParams, float roughness = 1.0 )
+{
+    float scale = _REBLUR_GetHitDistanceNormalization( viewZ, hitDistParams, roughness );
+
+    return normHitDist * scale;
+}
+
+float4 REBLUR_FrontEnd_PackRadianceAndNormHitDist( float3 radiance, float normHitDist, bool sanitize = true )
+{
+    if( sanitize )
+    {
+        radiance = _NRD_SanitizeRadiance( radiance );
+        normHitDist = ( isnan( normHitDist ) || isinf( normHitDist ) ) ? 0 : saturate( normHitDist );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( normHitDist != 0 )
+        normHitDist = max( normHitDist, NRD_FP16_MIN );
+
+    radiance = _NRD_LinearToYCoCg( radiance );
+
+    return float4( radiance, normHitDist );
+}
+
+float4 RELAX_FrontEnd_PackRadianceAndHitDist( float3 radiance, float hitDist, bool sanitize = true )
+{
+    if( sanitize )
+    {
+        radiance = _NRD_SanitizeRadiance( radiance );
+        hitDist = ( isnan( hitDist ) || isinf( hitDist ) ) ? 0 : clamp( hitDist, 0, NRD_FP16_MAX );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( hitDist != 0 )
+        hitDist = max( hitDist, NRD_FP16_MIN );
+
+    return float4( radiance, hitDist );
+}
+
+// "distanceToOccluder" is NRD_FP16_MAX if the shadow ray didn't hit anything, and 0 if the surface faces away from the light
+float2 SIGMA_FrontEnd_PackShadow( float viewZ, float distanceToOccluder, float tanOfLightAngularRadius )
+{
+    float2 r;
+    r.x = 0.0;
+    r.y = _NRD_PackViewZ( viewZ );
+
+    [flatten]
+    if( distanceToOccluder == NRD_FP16_MAX )
+        r.x = NRD_FP16_MAX;
+    else if( distanceToOccluder != 0.0 )
+    {
+        float distanceToOccluderProj = distanceToOccluder * tanOfLightAngularRadius;
+        r.x = clamp( distanceToOccluderProj, SIGMA_MIN_DISTANCE, 32768.0 );
+    }
+
+    return r;
+}
+
+float2 SIGMA_FrontEnd_PackShadow( float viewZ, float distanceToOccluder, float tanOfLightAngularRadius, float3 translucency, out float4 shadowTranslucency )
+{
+    shadowTranslucency.x = float( distanceToOccluder == NRD_FP16_MAX );
+    shadowTranslucency.yzw = saturate( translucency );
+
+    return SIGMA_FrontEnd_PackShadow( viewZ, distanceToOccluder, tanOfLightAngularRadius );
+}
+
+//=================================================================================================================================
+// BACK-END UNPACKING
+//=================================================================================================================================
+
+float4 REBLUR_BackEnd_UnpackRadianceAndNormHitDist( float4 data )
+{
+    data.xyz = _NRD_YCoCgToLinear( data.xyz );
+
+    return data;
+}
+
+float4 RELAX_BackEnd_UnpackRadiance( float4 data )
+{
+    return data;
+}
+
+float4 SIGMA_BackEnd_UnpackShadow( float4 shadow )
+{
+    return shadow * shadow;
+}
+
+//=================================================================================================================================
+// SPHERICAL HARMONICS
+//=================================================================================================================================
+
+struct NRD_SG
+{
+    float c0;
+    float2 chroma;
+    float hitDist; // REBLUR: normalized hit distance, RELAX: hit distance
+    float3 c1;
+};
+
+// "direction" is the normalized direction of the sample
+float4 REBLUR_FrontEnd_PackSh( float3 radiance, float normHitDist, float3 direction, out float4 out1, bool sanitize = true )
+{
+    float4 out0 = REBLUR_FrontEnd_PackRadianceAndNormHitDist( radiance, normHitDist, sanitize );
+    out1 = float4( direction * out0.x, 0 );
+
+    return out0;
+}
+
+float4 RELAX_FrontEnd_PackSh( float3 radiance, float hitDist, float3 direction, out float4 out1, bool sanitize = true )
+{
+    float4 out0 = RELAX_FrontEnd_PackRadianceAndHitDist( radiance, hitDist, sanitize );
+    out0.xyz = _NRD_LinearToYCoCg( out0.xyz );
+    out1 = float4( direction * out0.x, 0 );
+
+    return out0;
+}
+
+NRD_SG REBLUR_BackEnd_UnpackSh( float4 sh0, float4 sh1 )
+{
+    NRD_SG sg;
+    sg.c0 = sh0.x;
+    sg.chroma = sh0.yz;
+    sg.hitDist = sh0.w;
+    sg.c1 = sh1.xyz;
+
+    return sg;
+}
+
+NRD_SG RELAX_BackEnd_UnpackSh( float4 sh0, float4 sh1 )
+{
+    return REBLUR_BackEnd_UnpackSh( sh0, sh1 );
+}
+
+// "direction" is the normalized direction of the sample
+float4 REBLUR_FrontEnd_PackDirectionalOcclusion( float3 direction, float normHitDist, bool sanitize = true )
+{
+    if( sanitize )
+    {
+        direction = ( any( isnan( direction ) ) || any( isinf( direction ) ) ) ? 0 : direction;
+        normHitDist = ( isnan( normHitDist ) || isinf( normHitDist ) ) ? 0 : saturate( normHitDist );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( normHitDist != 0 )
+        normHitDist = max( normHitDist, NRD_FP16_MIN );
+
+    return float4( direction * normHitDist, normHitDist );
+}
+
+// The normalized hit distance is returned in both "c0" and "hitDist"
+NRD_SG REBLUR_BackEnd_UnpackDirectionalOcclusion( float4 data )
+{
+    NRD_SG sg;
+    sg.c0 = data.w;
+    sg.chroma = 0;
+    sg.hitDist = data.w;
+    sg.c1 = data.xyz;
+
+    return sg;
+}
+
+float3 NRD_SG_ExtractColor( NRD_SG sg )
+{
+    return _NRD_YCoCgToLinear( float3( sg.c0, sg.chroma ) );
+}
+
+float3 NRD_SG_ExtractDirection( NRD_SG sg )
+{
+    return _NRD_SafeNormalize( sg.c1 );
+}
+
+// Non-linear "Geomerics" reconstruction of L1 data
+float3 NRD_SH_ResolveDiffuse( NRD_SG sg, float3 N )
+{
+    float R0 = max( sg.c0, 0.0 );
+    float len = length( sg.c1 );
+    float r = min( len / ( R0 + NRD_EPS ), 1.0 - NRD_EPS );
+    float q = 0.5 + 0.5 * dot( N, sg.c1 ) / max( len, NRD_EPS );
+    float p = 1.0 + 2.0 * r;
+    float a = ( 1.0 - r ) / ( 1.0 + r );
+    float Y = R0 * ( a + ( 1.0 - a ) * ( p + 1.0 ) * pow( max( q, 0.0 ), p ) );
+
+    return _NRD_YCoCgToLinear_Corrected( Y, sg.c0, sg.chroma );
+}
+
+#endif
@@ -0,0 +1,362 @@
+// Packing functions matching the NRD binary linked by nrd-sys, WGSL port of NRD.hlsli.
+//
+// NRD_NORMAL_ENCODING and NRD_ROUGHNESS_ENCODING must be declared as "u32" constants in the same
+// module, use `nrd_sys::shader::ShaderLanguage::Wgsl.include( nrd_sys::Instance::library_desc( ) )`
+// to get a version with the encodings of the linked library.
+
+const NRD_NORMAL_ENCODING_RGBA8_UNORM: u32 = 0u;
+const NRD_NORMAL_ENCODING_RGBA8_SNORM: u32 = 1u;
+const NRD_NORMAL_ENCODING_R10G10B10A2_UNORM: u32 = 2u; // supports material ID bits
+const NRD_NORMAL_ENCODING_RGBA16_UNORM: u32 = 3u;
+const NRD_NORMAL_ENCODING_RGBA16_SNORM: u32 = 4u; // also can be used with FP formats
+
+const NRD_ROUGHNESS_ENCODING_SQ_LINEAR: u32 = 0u; // linearRoughness * linearRoughness
+const NRD_ROUGHNESS_ENCODING_LINEAR: u32 = 1u; // linearRoughness
+const NRD_ROUGHNESS_ENCODING_SQRT_LINEAR: u32 = 2u; // sqrt( linearRoughness )
+
+const NRD_FP16_MIN: f32 = 1e-7; // min allowed hitDist (0 = no data)
+const NRD_FP16_MAX: f32 = 65504.0;
+const NRD_EPS: f32 = 1e-6;
+const NRD_FP16_VIEWZ_SCALE: f32 = 0.125; // z in FP16 textures = viewZ * NRD_FP16_VIEWZ_SCALE
+const SIGMA_MIN_DISTANCE: f32 = 0.0001; // not 0, because it means "NoL < 0, stop processing"
+
+//=================================================================================================================================
+// PRIVATE
+//=================================================================================================================================
+
+// WGSL has no "isnan" / "isinf", test the exponent bits instead
+fn _NRD_IsNotFinite( x: f32 ) -> bool
+{
+    return ( bitcast<u32>( x ) & 0x7f800000u ) == 0x7f800000u;
+}
+
+fn _NRD_EncodeUnitVector( n: vec3<f32>, bSigned: bool ) -> vec2<f32>
+{
+    var v = n;
+    v = vec3<f32>( v.xy / dot( abs( v ), vec3<f32>( 1.0 ) ), v.z );
+
+    let octWrap = ( 1.0 - abs( v.yx ) ) * ( step( vec2<f32>( 0.0 ), v.xy ) * 2.0 - 1.0 );
+    let p = select( octWrap, v.xy, v.z >= 0.0 );
+
+    return select( p * 0.5 + 0.5, p, bSigned );
+}
+
+fn _NRD_DecodeUnitVector( e: vec2<f32>, bSigned: bool ) -> vec3<f32>
+{
+    let p = select( e * 2.0 - 1.0, e, bSigned );
+
+    // https://twitter.com/Stubbesaurus/status/937994790553227264
+    var n = vec3<f32>( p.xy, 1.0 - abs( p.x ) - abs( p.y ) );
+    let t = saturate( -n.z );
+    n = vec3<f32>( n.xy - t * ( step( vec2<f32>( 0.0 ), n.xy ) * 2.0 - 1.0 ), n.z );
+
+    return n;
+}
+
+fn _NRD_SafeNormalize( v: vec3<f32> ) -> vec3<f32>
+{
+    return v * inverseSqrt( max( dot( v, v ), NRD_EPS ) );
+}
+
+fn _NRD_LinearToYCoCg( color: vec3<f32> ) -> vec3<f32>
+{
+    let Y = dot( color, vec3<f32>( 0.25, 0.5, 0.25 ) );
+    let Co = dot( color, vec3<f32>( 0.5, 0.0, -0.5 ) );
+    let Cg = dot( color, vec3<f32>( -0.25, 0.5, -0.25 ) );
+
+    return vec3<f32>( Y, Co, Cg );
+}
+
+fn _NRD_YCoCgToLinear( color: vec3<f32> ) -> vec3<f32>
+{
+    let t = color.x - color.z;
+
+    var r: vec3<f32>;
+    r.y = color.x + color.z;
+    r.x = t + color.y;
+    r.z = t - color.y;
+
+    return max( r, vec3<f32>( 0.0 ) );
+}
+
+fn _NRD_YCoCgToLinear_Corrected( y: f32, Y0: f32, chroma: vec2<f32> ) -> vec3<f32>
+{
+    let Y = max( y, 0.0 );
+    let CoCg = chroma * ( Y + NRD_EPS ) / ( Y0 + NRD_EPS );
+
+    return _NRD_YCoCgToLinear( vec3<f32>( Y, CoCg ) );
+}
+
+fn _NRD_PackViewZ( z: f32 ) -> f32
+{
+    return clamp( z * NRD_FP16_VIEWZ_SCALE, -NRD_FP16_MAX, NRD_FP16_MAX );
+}
+
+fn _NRD_SanitizeRadiance( radiance: vec3<f32> ) -> vec3<f32>
+{
+    let bad = _NRD_IsNotFinite( radiance.x ) || _NRD_IsNotFinite( radiance.y ) || _NRD_IsNotFinite( radiance.z );
+
+    return select( clamp( radiance, vec3<f32>( 0.0 ), vec3<f32>( NRD_FP16_MAX ) ), vec3<f32>( 0.0 ), bad );
+}
+
+fn _REBLUR_GetHitDistanceNormalization( viewZ: f32, hitDistParams: vec4<f32>, roughness: f32 ) -> f32
+{
+    return ( hitDistParams.x + abs( viewZ ) * hitDistParams.y ) * mix( 1.0, hitDistParams.z, saturate( exp2( hitDistParams.w * roughness * roughness ) ) );
+}
+
+//=================================================================================================================================
+// FRONT-END PACKING
+//=================================================================================================================================
+
+fn NRD_FrontEnd_PackNormalAndRoughness( normal: vec3<f32>, roughness: f32, materialID: f32 ) -> vec4<f32>
+{
+    var linearRoughness = roughness;
+    if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQRT_LINEAR )
+    {
+        linearRoughness = sqrt( saturate( linearRoughness ) );
+    }
+    else if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQ_LINEAR )
+    {
+        linearRoughness *= linearRoughness;
+    }
+
+    if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_R10G10B10A2_UNORM )
+    {
+        return vec4<f32>( _NRD_EncodeUnitVector( normal, false ), linearRoughness, saturate( materialID / 3.0 ) );
+    }
+
+    // Best fit
+    var N = normal / max( abs( normal.x ), max( abs( normal.y ), abs( normal.z ) ) );
+
+    if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA8_UNORM || NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA16_UNORM )
+    {
+        N = N * 0.5 + 0.5;
+    }
+
+    return vec4<f32>( N, linearRoughness );
+}
+
+// Returns normal and linear roughness, "materialID" is in [0; 3]
+fn NRD_FrontEnd_UnpackNormalAndRoughness( p: vec4<f32>, materialID: ptr<function, f32> ) -> vec4<f32>
+{
+    var r: vec4<f32>;
+    if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_R10G10B10A2_UNORM )
+    {
+        r = vec4<f32>( _NRD_DecodeUnitVector( p.xy, false ), p.z );
+
+        *materialID = p.w * 3.0;
+    }
+    else
+    {
+        var n = p.xyz;
+        if( NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA8_UNORM || NRD_NORMAL_ENCODING == NRD_NORMAL_ENCODING_RGBA16_UNORM )
+        {
+            n = n * 2.0 - 1.0;
+        }
+
+        r = vec4<f32>( n, p.w );
+
+        *materialID = 0.0;
+    }
+
+    r = vec4<f32>( _NRD_SafeNormalize( r.xyz ), r.w );
+
+    if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQRT_LINEAR )
+    {
+        r.w *= r.w;
+    }
+    else if( NRD_ROUGHNESS_ENCODING == NRD_ROUGHNESS_ENCODING_SQ_LINEAR )
+    {
+        r.w = sqrt( saturate( r.w ) );
+    }
+
+    return r;
+}
+
+// "hitDistParams" is "HitDistanceParameters" packed as ( A, B, C, D ), use roughness = 1 if unknown
+fn REBLUR_FrontEnd_GetNormHitDist( hitDist: f32, viewZ: f32, hitDistParams: vec4<f32>, roughness: f32 ) -> f32
+{
+    let f = _REBLUR_GetHitDistanceNormalization( viewZ, hitDistParams, roughness );
+
+    return saturate( hitDist / f );
+}
+
+fn REBLUR_GetHitDist( normHitDist: f32, viewZ: f32, hitDistParams: vec4<f32>, roughness: f32 ) -> f32
+{
+    let scale = _REBLUR_GetHitDistanceNormalization( viewZ, hitDistParams, roughness );
+
+    return normHitDist * scale;
+}
+
+fn REBLUR_FrontEnd_PackRadianceAndNormHitDist( radiance: vec3<f32>, normHitDist: f32, sanitize: bool ) -> vec4<f32>
+{
+    var r = radiance;
+    var d = normHitDist;
+    if( sanitize )
+    {
+        r = _NRD_SanitizeRadiance( r );
+        d = select( saturate( d ), 0.0, _NRD_IsNotFinite( d ) );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( d != 0.0 )
+    {
+        d = max( d, NRD_FP16_MIN );
+    }
+
+    return vec4<f32>( _NRD_LinearToYCoCg( r ), d );
+}
+
+fn RELAX_FrontEnd_PackRadianceAndHitDist( radiance: vec3<f32>, hitDist: f32, sanitize: bool ) -> vec4<f32>
+{
+    var r = radiance;
+    var d = hitDist;
+    if( sanitize )
+    {
+        r = _NRD_SanitizeRadiance( r );
+        d = select( clamp( d, 0.0, NRD_FP16_MAX ), 0.0, _NRD_IsNotFinite( d ) );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( d != 0.0 )
+    {
+        d = max( d, NRD_FP16_MIN );
+    }
+
+    return vec4<f32>( r, d );
+}
+
+// "distanceToOccluder" is NRD_FP16_MAX if the shadow ray didn't hit anything, and 0 if the surface faces away from the light
+fn SIGMA_FrontEnd_PackShadow( viewZ: f32, distanceToOccluder: f32, tanOfLightAngularRadius: f32 ) -> vec2<f32>
+{
+    var r = vec2<f32>( 0.0, _NRD_PackViewZ( viewZ ) );
+
+    if( distanceToOccluder == NRD_FP16_MAX )
+    {
+        r.x = NRD_FP16_MAX;
+    }
+    else if( distanceToOccluder != 0.0 )
+    {
+        let distanceToOccluderProj = distanceToOccluder * tanOfLightAngularRadius;
+        r.x = clamp( distanceToOccluderProj, SIGMA_MIN_DISTANCE, 32768.0 );
+    }
+
+    return r;
+}
+
+fn SIGMA_FrontEnd_PackShadowTranslucency( viewZ: f32, distanceToOccluder: f32, tanOfLightAngularRadius: f32, translucency: vec3<f32>, shadowTranslucency: ptr<function, vec4<f32>> ) -> vec2<f32>
+{
+    *shadowTranslucency = vec4<f32>( select( 0.0, 1.0, distanceToOccluder == NRD_FP16_MAX ), saturate( translucency ) );
+
+    return SIGMA_FrontEnd_PackShadow( viewZ, distanceToOccluder, tanOfLightAngularRadius );
+}
+
+//=================================================================================================================================
+// BACK-END UNPACKING
+//=================================================================================================================================
+
+fn REBLUR_BackEnd_UnpackRadianceAndNormHitDist( data: vec4<f32> ) -> vec4<f32>
+{
+    return vec4<f32>( _NRD_YCoCgToLinear( data.xyz ), data.w );
+}
+
+fn RELAX_BackEnd_UnpackRadiance( data: vec4<f32> ) -> vec4<f32>
+{
+    return data;
+}
+
+fn SIGMA_BackEnd_UnpackShadow( shadow: vec4<f32> ) -> vec4<f32>
+{
+    return shadow * shadow;
+}
+
+//=================================================================================================================================
+// SPHERICAL HARMONICS
+//=================================================================================================================================
+
+struct NRD_SG
+{
+    c0: f32,
+    chroma: vec2<f32>,
+    hitDist: f32, // REBLUR: normalized hit distance, RELAX: hit distance
+    c1: vec3<f32>,
+};
+
+// "direction" is the normalized direction of the sample
+fn REBLUR_FrontEnd_PackSh( radiance: vec3<f32>, normHitDist: f32, direction: vec3<f32>, out1: ptr<function, vec4<f32>>, sanitize: bool ) -> vec4<f32>
+{
+    let out0 = REBLUR_FrontEnd_PackRadianceAndNormHitDist( radiance, normHitDist, sanitize );
+    *out1 = vec4<f32>( direction * out0.x, 0.0 );
+
+    return out0;
+}
+
+fn RELAX_FrontEnd_PackSh( radiance: vec3<f32>, hitDist: f32, direction: vec3<f32>, out1: ptr<function, vec4<f32>>, sanitize: bool ) -> vec4<f32>
+{
+    let packed = RELAX_FrontEnd_PackRadianceAndHitDist( radiance, hitDist, sanitize );
+    let out0 = vec4<f32>( _NRD_LinearToYCoCg( packed.xyz ), packed.w );
+    *out1 = vec4<f32>( direction * out0.x, 0.0 );
+
+    return out0;
+}
+
+fn REBLUR_BackEnd_UnpackSh( sh0: vec4<f32>, sh1: vec4<f32> ) -> NRD_SG
+{
+    return NRD_SG( sh0.x, sh0.yz, sh0.w, sh1.xyz );
+}
+
+fn RELAX_BackEnd_UnpackSh( sh0: vec4<f32>, sh1: vec4<f32> ) -> NRD_SG
+{
+    return REBLUR_BackEnd_UnpackSh( sh0, sh1 );
+}
+
+// "direction" is the normalized direction of the sample
+fn REBLUR_FrontEnd_PackDirectionalOcclusion( direction: vec3<f32>, normHitDist: f32, sanitize: bool ) -> vec4<f32>
+{
+    var v = direction;
+    var d = normHitDist;
+    if( sanitize )
+    {
+        let bad = _NRD_IsNotFinite( v.x ) || _NRD_IsNotFinite( v.y ) || _NRD_IsNotFinite( v.z );
+        v = select( v, vec3<f32>( 0.0 ), bad );
+        d = select( saturate( d ), 0.0, _NRD_IsNotFinite( d ) );
+    }
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if( d != 0.0 )
+    {
+        d = max( d, NRD_FP16_MIN );
+    }
+
+    return vec4<f32>( v * d, d );
+}
+
+// The normalized hit distance is returned in both "c0" and "hitDist"
+fn REBLUR_BackEnd_UnpackDirectionalOcclusion( data: vec4<f32> ) -> NRD_SG
+{
+    return NRD_SG( data.w, vec2<f32>( 0.0 ), data.w, data.xyz );
+}
+
+fn NRD_SG_ExtractColor( sg: NRD_SG ) -> vec3<f32>
+{
+    return _NRD_YCoCgToLinear( vec3<f32>( sg.c0, sg.chroma ) );
+}
+
+fn NRD_SG_ExtractDirection( sg: NRD_SG ) -> vec3<f32>
+{
+    return _NRD_SafeNormalize( sg.c1 );
+}
+
+// Non-linear "Geomerics" reconstruction of L1 data
+fn NRD_SH_ResolveDiffuse( sg: NRD_SG, N: vec3<f32> ) -> vec3<f32>
+{
+    let R0 = max( sg.c0, 0.0 );
+    let len = length( sg.c1 );
+    let r = min( len / ( R0 + NRD_EPS ), 1.0 - NRD_EPS );
+    let q = 0.5 + 0.5 * dot( N, sg.c1 ) / max( len, NRD_EPS );
+    let p = 1.0 + 2.0 * r;
+    let a = ( 1.0 - r ) / ( 1.0 + r );
+    let Y = R0 * ( a + ( 1.0 - a ) * ( p + 1.0 ) * pow( max( q, 0.0 ), p ) );
+
+    return _NRD_YCoCgToLinear_Corrected( Y, sg.c0, sg.chroma );
+}
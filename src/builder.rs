@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::family::DenoiserFamily;
+use crate::ffi::{self, Denoiser, DenoiserDesc, Identifier};
+use crate::{DenoiserSettings, Instance};
+
+// 0 is left for instances that weren't built by an `InstanceBuilder`
+static NEXT_BUILDER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A denoiser added through [`InstanceBuilder::add`], typed by its family so that
+/// [`Instance::set_settings`] only accepts the matching settings struct.
+///
+/// A handle is only valid for instances built from the builder that returned it; other instances
+/// reject it with `InvalidArgument`.
+pub struct Handle<F> {
+    builder: u64,
+    identifier: Identifier,
+    denoiser: Denoiser,
+    _family: PhantomData<fn() -> F>,
+}
+
+impl<F> Handle<F> {
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+    pub fn denoiser(&self) -> Denoiser {
+        self.denoiser
+    }
+}
+
+impl<F> Clone for Handle<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<F> Copy for Handle<F> {}
+
+impl<F> std::fmt::Debug for Handle<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Handle")
+            .field("identifier", &self.identifier)
+            .field("denoiser", &self.denoiser)
+            .finish()
+    }
+}
+
+impl<F> From<Handle<F>> for Identifier {
+    fn from(handle: Handle<F>) -> Self {
+        handle.identifier
+    }
+}
+
+/// Collects [`DenoiserDesc`]s with unique, automatically allocated identifiers.
+///
+/// A clone is a new builder: the handles of the original aren't valid for its instances.
+#[derive(Debug)]
+pub struct InstanceBuilder {
+    id: u64,
+    denoisers: Vec<DenoiserDesc>,
+}
+
+impl Default for InstanceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for InstanceBuilder {
+    fn clone(&self) -> Self {
+        Self {
+            id: NEXT_BUILDER_ID.fetch_add(1, Ordering::Relaxed),
+            denoisers: self.denoisers.clone(),
+        }
+    }
+}
+
+impl InstanceBuilder {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_BUILDER_ID.fetch_add(1, Ordering::Relaxed),
+            denoisers: Vec::new(),
+        }
+    }
+
+    /// Panics if `denoiser` doesn't belong to the family `F`.
+    pub fn add<F: DenoiserFamily>(
+        &mut self,
+        denoiser: Denoiser,
+        render_width: u16,
+        render_height: u16,
+    ) -> Handle<F> {
+        assert!(
+            F::contains(denoiser),
+            "{:?} doesn't belong to {}",
+            denoiser,
+            std::any::type_name::<F>()
+        );
+        let identifier = Identifier(self.denoisers.len() as u32);
+        self.denoisers.push(DenoiserDesc {
+            identifier,
+            denoiser,
+            render_width,
+            render_height,
+        });
+        Handle {
+            builder: self.id,
+            identifier,
+            denoiser,
+            _family: PhantomData,
+        }
+    }
+
+    pub fn denoisers(&self) -> &[DenoiserDesc] {
+        &self.denoisers
+    }
+
+    pub fn build(&self) -> Result<Instance, ffi::Result> {
+        let mut instance = Instance::new(&self.denoisers)?;
+        instance.builder = self.id;
+        Ok(instance)
+    }
+}
+
+impl Instance {
+    pub fn set_settings<F: DenoiserFamily>(
+        &mut self,
+        handle: Handle<F>,
+        settings: &F::Settings,
+    ) -> Result<(), ffi::Result>
+    where
+        F::Settings: DenoiserSettings,
+    {
+        self.check_handle(handle)?;
+        self.set_denoiser_settings(handle.identifier, settings)
+    }
+
+    /// [`Instance::get_compute_dispatches`] for a single denoiser.
+    pub fn get_dispatches<F>(
+        &mut self,
+        handle: Handle<F>,
+    ) -> Result<&[ffi::DispatchDesc], ffi::Result> {
+        self.check_handle(handle)?;
+        self.get_compute_dispatches(&[handle.identifier])
+    }
+
+    fn check_handle<F>(&self, handle: Handle<F>) -> Result<(), ffi::Result> {
+        if handle.builder == self.builder {
+            Ok(())
+        } else {
+            Err(ffi::Result::InvalidArgument)
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! Capture and replay of the calls made to an [`Instance`], for reproducing denoising artifacts.
+//!
+//! A [`Recorder`] wraps an instance and logs every call together with what NRD returned,
+//! including the instance description and the full dispatch lists with their constant buffers.
+//! [`replay`] re-issues the calls against a fresh instance of the linked library and reports
+//! every result that differs from the capture.
+
+use std::fmt::Display;
+use std::io::{Read, Write};
+
+use crate::ffi::{self, CommonSettings, DenoiserDesc, DispatchDesc, Identifier};
+use crate::{
+    AnyDenoiserSettings, DenoiserSettings, Instance, OwnedDispatchDesc, OwnedInstanceDesc,
+};
+
+const MAGIC: [u8; 4] = *b"NRDC";
+const VERSION: u32 = 1;
+
+/// A logged call with the arguments it was made with and what NRD returned.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Call {
+    CreateInstance {
+        denoisers: Vec<DenoiserDesc>,
+        desc: Result<OwnedInstanceDesc, ffi::Result>,
+    },
+    SetCommonSettings {
+        settings: Box<CommonSettings>,
+        result: Result<(), ffi::Result>,
+    },
+    SetDenoiserSettings {
+        identifier: Identifier,
+        settings: AnyDenoiserSettings,
+        result: Result<(), ffi::Result>,
+    },
+    GetComputeDispatches {
+        identifiers: Vec<Identifier>,
+        dispatches: Result<Vec<OwnedDispatchDesc>, ffi::Result>,
+    },
+}
+
+/// The calls logged by a [`Recorder`], in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Capture {
+    pub calls: Vec<Call>,
+}
+
+/// Why [`Capture::load`] or [`Capture::save`] failed.
+#[derive(Debug)]
+pub enum CaptureError {
+    Io(std::io::Error),
+    Encoding(String),
+    NotACapture,
+    UnsupportedVersion(u32),
+}
+
+impl Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureError::Io(err) => err.fmt(f),
+            CaptureError::Encoding(err) => f.write_str(err),
+            CaptureError::NotACapture => f.write_str("not an NRD capture"),
+            CaptureError::UnsupportedVersion(version) => {
+                write!(f, "unsupported capture version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<std::io::Error> for CaptureError {
+    fn from(err: std::io::Error) -> Self {
+        CaptureError::Io(err)
+    }
+}
+
+impl From<bincode::Error> for CaptureError {
+    fn from(err: bincode::Error) -> Self {
+        match *err {
+            bincode::ErrorKind::Io(err) => CaptureError::Io(err),
+            err => CaptureError::Encoding(err.to_string()),
+        }
+    }
+}
+
+impl Capture {
+    /// Writes the capture to `writer`, in a versioned binary format.
+    pub fn save(&self, mut writer: impl Write) -> Result<(), CaptureError> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        bincode::serialize_into(writer, self)?;
+        Ok(())
+    }
+
+    /// Reads a capture written by [`Capture::save`]. Captures of another format version are
+    /// rejected with [`CaptureError::UnsupportedVersion`].
+    pub fn load(mut reader: impl Read) -> Result<Self, CaptureError> {
+        let mut header = [0; 8];
+        reader.read_exact(&mut header)?;
+        if header[..4] != MAGIC {
+            return Err(CaptureError::NotACapture);
+        }
+        let version = u32::from_le_bytes(header[4..].try_into().unwrap());
+        if version != VERSION {
+            return Err(CaptureError::UnsupportedVersion(version));
+        }
+        Ok(bincode::deserialize_from(reader)?)
+    }
+}
+
+/// An [`Instance`] that logs every call into a [`Capture`].
+pub struct Recorder {
+    instance: Instance,
+    capture: Capture,
+}
+
+impl Recorder {
+    /// Creates the instance and logs its creation. If NRD fails to create it, the error comes
+    /// with a capture holding just the failed call, so that it can be saved and replayed too.
+    pub fn new(denoisers: &[DenoiserDesc]) -> Result<Self, (ffi::Result, Capture)> {
+        let instance = Instance::new(denoisers);
+        let capture = Capture {
+            calls: vec![Call::CreateInstance {
+                denoisers: denoisers.to_vec(),
+                desc: instance
+                    .as_ref()
+                    .map(|instance| instance.desc().into())
+                    .map_err(|&err| err),
+            }],
+        };
+        match instance {
+            Ok(instance) => Ok(Self { instance, capture }),
+            Err(err) => Err((err, capture)),
+        }
+    }
+
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    pub fn capture(&self) -> &Capture {
+        &self.capture
+    }
+
+    pub fn into_capture(self) -> Capture {
+        self.capture
+    }
+
+    pub fn set_common_settings(&mut self, settings: &CommonSettings) -> Result<(), ffi::Result> {
+        let result = self.instance.set_common_settings(settings);
+        self.capture.calls.push(Call::SetCommonSettings {
+            settings: Box::new(settings.clone()),
+            result,
+        });
+        result
+    }
+
+    pub fn set_denoiser_settings<T: DenoiserSettings>(
+        &mut self,
+        identifier: Identifier,
+        settings: &T,
+    ) -> Result<(), ffi::Result> {
+        let result = self.instance.set_denoiser_settings(identifier, settings);
+        self.capture.calls.push(Call::SetDenoiserSettings {
+            identifier,
+            settings: settings.clone().into(),
+            result,
+        });
+        result
+    }
+
+    pub fn get_compute_dispatches(
+        &mut self,
+        identifiers: &[Identifier],
+    ) -> Result<&[DispatchDesc], ffi::Result> {
+        let result = self.instance.get_compute_dispatches(identifiers);
+        self.capture.calls.push(Call::GetComputeDispatches {
+            identifiers: identifiers.to_vec(),
+            dispatches: result
+                .as_ref()
+                .map(|dispatches| dispatches.iter().map(Into::into).collect())
+                .map_err(|&err| err),
+        });
+        result
+    }
+}
+
+/// A replayed call whose result differs from the capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    // Index into `Capture::calls`
+    pub call: usize,
+    pub description: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "call {}: {}", self.call, self.description)
+    }
+}
+
+/// Re-issues the captured calls against fresh instances and diffs the results.
+///
+/// Calls following an instance that failed to be created are skipped until the next
+/// `CreateInstance`. Settings that don't match the captured denoiser of their identifier are
+/// reported instead of being forwarded to NRD.
+pub fn replay(capture: &Capture) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    let mut instance = None;
+    let mut created: &[DenoiserDesc] = &[];
+    for (call, recorded) in capture.calls.iter().enumerate() {
+        let mut mismatch = |description: String| mismatches.push(Mismatch { call, description });
+        match recorded {
+            Call::CreateInstance { denoisers, desc } => {
+                created = denoisers;
+                instance = Instance::new(denoisers).ok();
+                let replayed = instance
+                    .as_ref()
+                    .map(|instance| OwnedInstanceDesc::from(instance.desc()))
+                    .ok_or(ffi::Result::Failure);
+                match (desc, &replayed) {
+                    (Ok(desc), Ok(replayed)) => diff_instance_desc(desc, replayed, &mut mismatch),
+                    (Err(_), Err(_)) => {}
+                    _ => mismatch(format!(
+                        "instance creation {} but was {}",
+                        outcome(desc),
+                        outcome(&replayed)
+                    )),
+                }
+            }
+            _ if instance.is_none() => {}
+            Call::SetCommonSettings { settings, result } => {
+                let replayed = instance.as_mut().unwrap().set_common_settings(settings);
+                if replayed != *result {
+                    mismatch(format!(
+                        "SetCommonSettings returned {replayed:?}, captured {result:?}"
+                    ));
+                }
+            }
+            Call::SetDenoiserSettings {
+                identifier,
+                settings,
+                result,
+            } => {
+                let denoiser = created
+                    .iter()
+                    .find(|desc| desc.identifier == *identifier)
+                    .map(|desc| desc.denoiser);
+                if denoiser.and_then(ffi::Denoiser::settings_kind) != Some(settings.kind()) {
+                    // A capture can only hold this as a call the recorder saw being rejected
+                    if *result != Err(ffi::Result::InvalidArgument) {
+                        mismatch(format!(
+                            "{:?} settings don't apply to denoiser {} ({denoiser:?})",
+                            settings.kind(),
+                            identifier.0
+                        ));
+                    }
+                    continue;
+                }
+                let replayed = settings.apply(instance.as_mut().unwrap(), *identifier);
+                if replayed != *result {
+                    mismatch(format!(
+                        "SetDenoiserSettings returned {replayed:?}, captured {result:?}"
+                    ));
+                }
+            }
+            Call::GetComputeDispatches {
+                identifiers,
+                dispatches,
+            } => {
+                let replayed = instance
+                    .as_mut()
+                    .unwrap()
+                    .get_compute_dispatches(identifiers);
+                match (dispatches, replayed) {
+                    (Ok(captured), Ok(replayed)) => {
+                        diff_dispatches(captured, replayed, &mut mismatch)
+                    }
+                    (Err(captured), Err(replayed)) if *captured == replayed => {}
+                    (captured, replayed) => mismatch(format!(
+                        "GetComputeDispatches {} but was {}",
+                        outcome(captured),
+                        outcome(&replayed)
+                    )),
+                }
+            }
+        }
+    }
+    mismatches
+}
+
+fn outcome<T>(result: &Result<T, ffi::Result>) -> String {
+    match result {
+        Ok(_) => "succeeded".to_string(),
+        Err(err) => format!("failed with {err:?}"),
+    }
+}
+
+fn diff_instance_desc(
+    captured: &OwnedInstanceDesc,
+    replayed: &OwnedInstanceDesc,
+    mismatch: &mut impl FnMut(String),
+) {
+    if captured.pipelines.len() != replayed.pipelines.len() {
+        mismatch(format!(
+            "{} pipelines, captured {}",
+            replayed.pipelines.len(),
+            captured.pipelines.len()
+        ));
+    } else {
+        for (i, (a, b)) in captured
+            .pipelines
+            .iter()
+            .zip(&replayed.pipelines)
+            .enumerate()
+        {
+            if a != b {
+                mismatch(format!("pipeline {i} ({}) differs", a.shader_file_name));
+            }
+        }
+    }
+    if captured.permanent_pool != replayed.permanent_pool {
+        mismatch("permanent pool differs".to_string());
+    }
+    if captured.transient_pool != replayed.transient_pool {
+        mismatch("transient pool differs".to_string());
+    }
+    if captured.samplers != replayed.samplers
+        || captured.descriptor_pool_desc != replayed.descriptor_pool_desc
+        || captured.constant_buffer_max_data_size != replayed.constant_buffer_max_data_size
+    {
+        mismatch("samplers, descriptor pool or constant buffer size differ".to_string());
+    }
+}
+
+fn diff_dispatches(
+    captured: &[OwnedDispatchDesc],
+    replayed: &[DispatchDesc],
+    mismatch: &mut impl FnMut(String),
+) {
+    if captured.len() != replayed.len() {
+        mismatch(format!(
+            "{} dispatches, captured {}",
+            replayed.len(),
+            captured.len()
+        ));
+        return;
+    }
+    for (i, (a, b)) in captured.iter().zip(replayed).enumerate() {
+        let b = OwnedDispatchDesc::from(b);
+        let field = if a.name != b.name {
+            "name"
+        } else if a.pipeline_index != b.pipeline_index {
+            "pipeline index"
+        } else if (a.grid_width, a.grid_height) != (b.grid_width, b.grid_height) {
+            "grid size"
+        } else if a.resources != b.resources {
+            "resources"
+        } else if a.constant_buffer != b.constant_buffer {
+            "constant buffer"
+        } else {
+            continue;
+        };
+        mismatch(format!("dispatch {i} ({}) {field} differs", a.name));
+    }
+}
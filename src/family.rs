@@ -0,0 +1,65 @@
+//! Marker types grouping the [`Denoiser`] variants that share a settings struct.
+//!
+//! Used with [`crate::InstanceBuilder::add`] so that [`crate::Handle`]s only accept the matching
+//! settings.
+
+use crate::ffi::{
+    Denoiser, ReblurSettings, ReferenceSettings, RelaxDiffuseSettings,
+    RelaxDiffuseSpecularSettings, RelaxSpecularSettings, SigmaSettings,
+};
+use crate::SettingsKind;
+
+pub trait DenoiserFamily {
+    // `()` for denoisers without settings
+    type Settings;
+
+    // `None` for denoisers without settings
+    const KIND: Option<SettingsKind>;
+
+    fn contains(denoiser: Denoiser) -> bool {
+        denoiser.settings_kind() == Self::KIND
+    }
+}
+
+pub struct Reblur;
+pub struct RelaxDiffuse;
+pub struct RelaxSpecular;
+pub struct RelaxDiffuseSpecular;
+pub struct Sigma;
+pub struct Reference;
+pub struct SpecularMotion;
+
+impl DenoiserFamily for Reblur {
+    type Settings = ReblurSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::Reblur);
+}
+
+impl DenoiserFamily for RelaxDiffuse {
+    type Settings = RelaxDiffuseSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::RelaxDiffuse);
+}
+
+impl DenoiserFamily for RelaxSpecular {
+    type Settings = RelaxSpecularSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::RelaxSpecular);
+}
+
+impl DenoiserFamily for RelaxDiffuseSpecular {
+    type Settings = RelaxDiffuseSpecularSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::RelaxDiffuseSpecular);
+}
+
+impl DenoiserFamily for Sigma {
+    type Settings = SigmaSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::Sigma);
+}
+
+impl DenoiserFamily for Reference {
+    type Settings = ReferenceSettings;
+    const KIND: Option<SettingsKind> = Some(SettingsKind::Reference);
+}
+
+impl DenoiserFamily for SpecularMotion {
+    type Settings = ();
+    const KIND: Option<SettingsKind> = None;
+}
@@ -5,6 +5,7 @@ use std::{
 
 #[repr(C)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SPIRVBindingOffsets {
     pub sampler_offset: u32,
     pub texture_offset: u32,
@@ -13,7 +14,8 @@ pub struct SPIRVBindingOffsets {
 }
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Denoiser {
     /*
     IMPORTANT: IN_MV, IN_NORMAL_ROUGHNESS, IN_VIEWZ are used by any denoiser
@@ -140,7 +142,8 @@ pub enum Denoiser {
 }
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NormalEncoding {
     // Worst IQ on curved (not bumpy) surfaces
     Rgba8Unorm,
@@ -156,7 +159,8 @@ pub enum NormalEncoding {
 
 /// NRD_ROUGHNESS_ENCODING variants
 #[repr(u8)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RoughnessEncoding {
     // Alpha (m)
     SqLinear,
@@ -206,7 +210,8 @@ impl Debug for LibraryDesc {
 }
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Result {
     Success,
     Failure,
@@ -225,10 +230,14 @@ impl Result {
 }
 
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Identifier(pub u32);
 
 #[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DenoiserDesc {
     pub identifier: Identifier,
     pub denoiser: Denoiser,
@@ -261,7 +270,8 @@ pub(crate) struct InstanceCreationDesc {
 }
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sampler {
     NearestClamp,
     NearestMirroredRepeat,
@@ -282,12 +292,17 @@ impl Debug for ComputeShaderDesc {
 impl std::ops::Deref for ComputeShaderDesc {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
+        // Bytecode for a shader language the library wasn't built with is null
+        if self.bytecode.is_null() {
+            return &[];
+        }
         unsafe { std::slice::from_raw_parts(self.bytecode as *const u8, self.size as usize) }
     }
 }
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DescriptorType {
     // read-only, SRV
     Texture,
@@ -297,7 +312,8 @@ pub enum DescriptorType {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResourceRangeDesc {
     pub descriptor_type: DescriptorType,
     pub base_register_index: u32,
@@ -305,8 +321,9 @@ pub struct ResourceRangeDesc {
 }
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Format {
     R8_UNORM,
     R8_SNORM,
@@ -403,7 +420,8 @@ impl Debug for PipelineDesc {
 }
 
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextureDesc {
     pub format: Format,
     pub width: u16,
@@ -412,7 +430,8 @@ pub struct TextureDesc {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DescriptorPoolDesc {
     pub sets_max_num: u32,
     pub constant_buffers_max_num: u32,
@@ -500,7 +519,8 @@ impl Debug for InstanceDesc {
 }
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccumulationMode {
     // Common mode (accumulation continues normally)
     Continue,
@@ -513,7 +533,9 @@ pub enum AccumulationMode {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct CommonSettings {
     // Matrix requirements:
     //     - usage - vector is a column
@@ -626,7 +648,8 @@ impl Default for CommonSettings {
 
 #[repr(u32)]
 #[allow(non_camel_case_types)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResourceType {
     //=============================================================================================================================
     // COMMON INPUTS
@@ -752,7 +775,8 @@ pub enum ResourceType {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResourceDesc {
     pub state_needed: DescriptorType,
     pub ty: ResourceType,
@@ -806,7 +830,9 @@ impl Debug for DispatchDesc {
 }
 
 #[repr(C)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct HitDistanceParameters {
     // (units) - constant value
     // IMPORTANT: if your unit is not "meter", you must convert it from "meters" to "units" manually!
@@ -833,13 +859,18 @@ impl Default for HitDistanceParameters {
     }
 }
 
+pub const REBLUR_MAX_HISTORY_FRAME_NUM: u32 = 63;
+pub const RELAX_MAX_HISTORY_FRAME_NUM: u32 = 255;
+
 // Antilag logic:
 //    delta = ( abs( old - new ) - localVariance * sigmaScale ) / ( max( old, new ) + localVariance * sigmaScale + sensitivityToDarkness )
 //    delta = LinearStep( thresholdMax, thresholdMin, delta )
 //        - 1 - keep accumulation
 //        - 0 - history reset
 #[repr(C)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ReblurAntilagSettings {
     // [1; 3] - delta is reduced by local variance multiplied by this value
     pub luminance_sigma_scale: f32,
@@ -862,7 +893,8 @@ impl Default for ReblurAntilagSettings {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CheckerboardMode {
     Off,
     Black,
@@ -870,7 +902,8 @@ pub enum CheckerboardMode {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HitDistanceReconstructionMode {
     // Probabilistic split at primary hit is not used, hence hit distance is always valid (reconstruction is not needed)
     Off,
@@ -884,7 +917,9 @@ pub enum HitDistanceReconstructionMode {
     Area5x5,
 }
 #[repr(C)]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ReblurSettings {
     pub hit_distance_parameters: HitDistanceParameters,
     pub antilag_settings: ReblurAntilagSettings,
@@ -985,6 +1020,9 @@ impl Default for ReblurSettings {
 }
 
 #[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SigmaSettings {
     // (normalized %) - represents maximum allowed deviation from local tangent plane
     pub plane_distance_sensitivity: f32,
@@ -1002,21 +1040,24 @@ impl Default for SigmaSettings {
 }
 
 #[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RelaxAntilagSettings {
     // IMPORTANT: History acceleration and reset amounts for specular are made 2x-3x weaker than values for diffuse below
     // due to specific specular logic that does additional history acceleration and reset
 
     // [0; 1] - amount of history acceleration if history clamping happened in pixel
-    acceleration_amount: f32,
+    pub acceleration_amount: f32,
 
     // (> 0) - history is being reset if delta between history and raw input is larger than spatial sigma + temporal sigma
-    spatial_sigma_scale: f32,
+    pub spatial_sigma_scale: f32,
 
     // (> 0) - history is being reset if delta between history and raw input is larger than spatial sigma + temporal sigma
-    temporal_sigma_scale: f32,
+    pub temporal_sigma_scale: f32,
 
     // [0; 1] - amount of history reset, 0.0 - no reset, 1.0 - full reset
-    reset_amount: f32,
+    pub reset_amount: f32,
 }
 
 impl Default for RelaxAntilagSettings {
@@ -1032,7 +1073,9 @@ impl Default for RelaxAntilagSettings {
 
 // RELAX_DIFFUSE_SPECULAR
 #[repr(C)]
-
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RelaxDiffuseSpecularSettings {
     pub antilag_settings: RelaxAntilagSettings,
     // (pixels) - pre-accumulation spatial reuse pass blur radius (0 = disabled, must be used in case of probabilistic sampling)
@@ -1167,6 +1210,9 @@ impl Default for RelaxDiffuseSpecularSettings {
 // RELAX_DIFFUSE
 
 #[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RelaxDiffuseSettings {
     pub antilag_settings: RelaxAntilagSettings,
     pub prepass_blur_radius: f32,
@@ -1232,6 +1278,9 @@ impl Default for RelaxDiffuseSettings {
 // RELAX_SPECULAR
 
 #[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RelaxSpecularSettings {
     pub antilag_settings: RelaxAntilagSettings,
     pub prepass_blur_radius: f32,
@@ -1312,6 +1361,9 @@ impl Default for RelaxSpecularSettings {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ReferenceSettings {
     // (>= 0) - maximum number of linearly accumulated frames ( = FPS * "time of accumulation")
     pub max_accumulated_frame_num: u32,
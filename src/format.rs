@@ -0,0 +1,290 @@
+use std::fmt::Display;
+
+use crate::ffi::Format;
+
+/// How the channels of a [`Format`] are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericType {
+    Unorm,
+    Snorm,
+    Uint,
+    Sint,
+    Float,
+    // Unsigned floating point without a sign bit
+    Ufloat,
+}
+
+/// A [`Format`] that can't be used the way NRD needs it on some graphics API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    pub format: Format,
+    pub hint: &'static str,
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.format, self.hint)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl Format {
+    /// Size of a texel in bytes. All formats used by NRD are uncompressed, so this is also the
+    /// size of a block.
+    pub fn bytes_per_pixel(self) -> u32 {
+        use Format::*;
+        match self {
+            R8_UNORM | R8_SNORM | R8_UINT | R8_SINT => 1,
+            RG8_UNORM | RG8_SNORM | RG8_UINT | RG8_SINT => 2,
+            R16_UNORM | R16_SNORM | R16_UINT | R16_SINT | R16_SFLOAT => 2,
+            RGBA8_UNORM | RGBA8_SNORM | RGBA8_UINT | RGBA8_SINT | RGBA8_SRGB => 4,
+            RG16_UNORM | RG16_SNORM | RG16_UINT | RG16_SINT | RG16_SFLOAT => 4,
+            R32_UINT | R32_SINT | R32_SFLOAT => 4,
+            R10_G10_B10_A2_UNORM | R10_G10_B10_A2_UINT | R11_G11_B10_UFLOAT
+            | R9_G9_B9_E5_UFLOAT => 4,
+            RGBA16_UNORM | RGBA16_SNORM | RGBA16_UINT | RGBA16_SINT | RGBA16_SFLOAT => 8,
+            RG32_UINT | RG32_SINT | RG32_SFLOAT => 8,
+            RGB32_UINT | RGB32_SINT | RGB32_SFLOAT => 12,
+            RGBA32_UINT | RGBA32_SINT | RGBA32_SFLOAT => 16,
+        }
+    }
+
+    /// Width and height of a block in texels, always 1x1 as NRD uses no compressed formats.
+    pub fn block_extent(self) -> [u32; 2] {
+        [1, 1]
+    }
+
+    pub fn channel_count(self) -> u32 {
+        use Format::*;
+        match self {
+            R8_UNORM | R8_SNORM | R8_UINT | R8_SINT => 1,
+            R16_UNORM | R16_SNORM | R16_UINT | R16_SINT | R16_SFLOAT => 1,
+            R32_UINT | R32_SINT | R32_SFLOAT => 1,
+            RG8_UNORM | RG8_SNORM | RG8_UINT | RG8_SINT => 2,
+            RG16_UNORM | RG16_SNORM | RG16_UINT | RG16_SINT | RG16_SFLOAT => 2,
+            RG32_UINT | RG32_SINT | RG32_SFLOAT => 2,
+            RGB32_UINT | RGB32_SINT | RGB32_SFLOAT => 3,
+            R11_G11_B10_UFLOAT | R9_G9_B9_E5_UFLOAT => 3,
+            RGBA8_UNORM | RGBA8_SNORM | RGBA8_UINT | RGBA8_SINT | RGBA8_SRGB => 4,
+            RGBA16_UNORM | RGBA16_SNORM | RGBA16_UINT | RGBA16_SINT | RGBA16_SFLOAT => 4,
+            RGBA32_UINT | RGBA32_SINT | RGBA32_SFLOAT => 4,
+            R10_G10_B10_A2_UNORM | R10_G10_B10_A2_UINT => 4,
+        }
+    }
+
+    /// sRGB formats report [`NumericType::Unorm`], see [`Format::is_srgb`].
+    pub fn numeric_type(self) -> NumericType {
+        use Format::*;
+        match self {
+            R8_UNORM | RG8_UNORM | RGBA8_UNORM | RGBA8_SRGB | R16_UNORM | RG16_UNORM
+            | RGBA16_UNORM | R10_G10_B10_A2_UNORM => NumericType::Unorm,
+            R8_SNORM | RG8_SNORM | RGBA8_SNORM | R16_SNORM | RG16_SNORM | RGBA16_SNORM => {
+                NumericType::Snorm
+            }
+            R8_UINT | RG8_UINT | RGBA8_UINT | R16_UINT | RG16_UINT | RGBA16_UINT | R32_UINT
+            | RG32_UINT | RGB32_UINT | RGBA32_UINT | R10_G10_B10_A2_UINT => NumericType::Uint,
+            R8_SINT | RG8_SINT | RGBA8_SINT | R16_SINT | RG16_SINT | RGBA16_SINT | R32_SINT
+            | RG32_SINT | RGB32_SINT | RGBA32_SINT => NumericType::Sint,
+            R16_SFLOAT | RG16_SFLOAT | RGBA16_SFLOAT | R32_SFLOAT | RG32_SFLOAT | RGB32_SFLOAT
+            | RGBA32_SFLOAT => NumericType::Float,
+            R11_G11_B10_UFLOAT | R9_G9_B9_E5_UFLOAT => NumericType::Ufloat,
+        }
+    }
+
+    pub fn is_srgb(self) -> bool {
+        self == Format::RGBA8_SRGB
+    }
+
+    /// Checks that the format can back a storage image, which NRD needs for every pool texture.
+    pub fn storage_support(self) -> Result<(), FormatError> {
+        use Format::*;
+        let hint = match self {
+            RGBA8_SRGB => "sRGB formats can't be storage images, use RGBA8_UNORM instead",
+            RGB32_UINT | RGB32_SINT | RGB32_SFLOAT => {
+                "three channel 32-bit formats can't be storage images, use the RGBA32 variant instead"
+            }
+            R9_G9_B9_E5_UFLOAT => {
+                "shared exponent formats can't be storage images, use R11_G11_B10_UFLOAT or RGBA16_SFLOAT instead"
+            }
+            _ => return Ok(()),
+        };
+        Err(FormatError { format: self, hint })
+    }
+
+    /// The matching `DXGI_FORMAT` value.
+    pub fn to_dxgi(self) -> u32 {
+        use Format::*;
+        match self {
+            R8_UNORM => 61,
+            R8_SNORM => 63,
+            R8_UINT => 62,
+            R8_SINT => 64,
+            RG8_UNORM => 49,
+            RG8_SNORM => 51,
+            RG8_UINT => 50,
+            RG8_SINT => 52,
+            RGBA8_UNORM => 28,
+            RGBA8_SNORM => 31,
+            RGBA8_UINT => 30,
+            RGBA8_SINT => 32,
+            RGBA8_SRGB => 29,
+            R16_UNORM => 56,
+            R16_SNORM => 58,
+            R16_UINT => 57,
+            R16_SINT => 59,
+            R16_SFLOAT => 54,
+            RG16_UNORM => 35,
+            RG16_SNORM => 37,
+            RG16_UINT => 36,
+            RG16_SINT => 38,
+            RG16_SFLOAT => 34,
+            RGBA16_UNORM => 11,
+            RGBA16_SNORM => 13,
+            RGBA16_UINT => 12,
+            RGBA16_SINT => 14,
+            RGBA16_SFLOAT => 10,
+            R32_UINT => 42,
+            R32_SINT => 43,
+            R32_SFLOAT => 41,
+            RG32_UINT => 17,
+            RG32_SINT => 18,
+            RG32_SFLOAT => 16,
+            RGB32_UINT => 7,
+            RGB32_SINT => 8,
+            RGB32_SFLOAT => 6,
+            RGBA32_UINT => 3,
+            RGBA32_SINT => 4,
+            RGBA32_SFLOAT => 2,
+            R10_G10_B10_A2_UNORM => 24,
+            R10_G10_B10_A2_UINT => 25,
+            R11_G11_B10_UFLOAT => 26,
+            R9_G9_B9_E5_UFLOAT => 67,
+        }
+    }
+
+    #[cfg(feature = "ash")]
+    pub fn to_vk(self) -> ash::vk::Format {
+        use ash::vk;
+        use Format::*;
+        match self {
+            R8_UNORM => vk::Format::R8_UNORM,
+            R8_SNORM => vk::Format::R8_SNORM,
+            R8_UINT => vk::Format::R8_UINT,
+            R8_SINT => vk::Format::R8_SINT,
+            RG8_UNORM => vk::Format::R8G8_UNORM,
+            RG8_SNORM => vk::Format::R8G8_SNORM,
+            RG8_UINT => vk::Format::R8G8_UINT,
+            RG8_SINT => vk::Format::R8G8_SINT,
+            RGBA8_UNORM => vk::Format::R8G8B8A8_UNORM,
+            RGBA8_SNORM => vk::Format::R8G8B8A8_SNORM,
+            RGBA8_UINT => vk::Format::R8G8B8A8_UINT,
+            RGBA8_SINT => vk::Format::R8G8B8A8_SINT,
+            RGBA8_SRGB => vk::Format::R8G8B8A8_SRGB,
+            R16_UNORM => vk::Format::R16_UNORM,
+            R16_SNORM => vk::Format::R16_SNORM,
+            R16_UINT => vk::Format::R16_UINT,
+            R16_SINT => vk::Format::R16_SINT,
+            R16_SFLOAT => vk::Format::R16_SFLOAT,
+            RG16_UNORM => vk::Format::R16G16_UNORM,
+            RG16_SNORM => vk::Format::R16G16_SNORM,
+            RG16_UINT => vk::Format::R16G16_UINT,
+            RG16_SINT => vk::Format::R16G16_SINT,
+            RG16_SFLOAT => vk::Format::R16G16_SFLOAT,
+            RGBA16_UNORM => vk::Format::R16G16B16A16_UNORM,
+            RGBA16_SNORM => vk::Format::R16G16B16A16_SNORM,
+            RGBA16_UINT => vk::Format::R16G16B16A16_UINT,
+            RGBA16_SINT => vk::Format::R16G16B16A16_SINT,
+            RGBA16_SFLOAT => vk::Format::R16G16B16A16_SFLOAT,
+            R32_UINT => vk::Format::R32_UINT,
+            R32_SINT => vk::Format::R32_SINT,
+            R32_SFLOAT => vk::Format::R32_SFLOAT,
+            RG32_UINT => vk::Format::R32G32_UINT,
+            RG32_SINT => vk::Format::R32G32_SINT,
+            RG32_SFLOAT => vk::Format::R32G32_SFLOAT,
+            RGB32_UINT => vk::Format::R32G32B32_UINT,
+            RGB32_SINT => vk::Format::R32G32B32_SINT,
+            RGB32_SFLOAT => vk::Format::R32G32B32_SFLOAT,
+            RGBA32_UINT => vk::Format::R32G32B32A32_UINT,
+            RGBA32_SINT => vk::Format::R32G32B32A32_SINT,
+            RGBA32_SFLOAT => vk::Format::R32G32B32A32_SFLOAT,
+            // Vulkan names packed formats from the most significant bits
+            R10_G10_B10_A2_UNORM => vk::Format::A2B10G10R10_UNORM_PACK32,
+            R10_G10_B10_A2_UINT => vk::Format::A2B10G10R10_UINT_PACK32,
+            R11_G11_B10_UFLOAT => vk::Format::B10G11R11_UFLOAT_PACK32,
+            R9_G9_B9_E5_UFLOAT => vk::Format::E5B9G9R9_UFLOAT_PACK32,
+        }
+    }
+
+    /// Fails for the three channel 32-bit formats, which WebGPU doesn't have. Some formats also
+    /// need device features, see [`Format::wgpu_features`].
+    #[cfg(feature = "wgpu-types")]
+    pub fn to_wgpu(self) -> Result<wgpu_types::TextureFormat, FormatError> {
+        use wgpu_types::TextureFormat as F;
+        use Format::*;
+        Ok(match self {
+            R8_UNORM => F::R8Unorm,
+            R8_SNORM => F::R8Snorm,
+            R8_UINT => F::R8Uint,
+            R8_SINT => F::R8Sint,
+            RG8_UNORM => F::Rg8Unorm,
+            RG8_SNORM => F::Rg8Snorm,
+            RG8_UINT => F::Rg8Uint,
+            RG8_SINT => F::Rg8Sint,
+            RGBA8_UNORM => F::Rgba8Unorm,
+            RGBA8_SNORM => F::Rgba8Snorm,
+            RGBA8_UINT => F::Rgba8Uint,
+            RGBA8_SINT => F::Rgba8Sint,
+            RGBA8_SRGB => F::Rgba8UnormSrgb,
+            R16_UNORM => F::R16Unorm,
+            R16_SNORM => F::R16Snorm,
+            R16_UINT => F::R16Uint,
+            R16_SINT => F::R16Sint,
+            R16_SFLOAT => F::R16Float,
+            RG16_UNORM => F::Rg16Unorm,
+            RG16_SNORM => F::Rg16Snorm,
+            RG16_UINT => F::Rg16Uint,
+            RG16_SINT => F::Rg16Sint,
+            RG16_SFLOAT => F::Rg16Float,
+            RGBA16_UNORM => F::Rgba16Unorm,
+            RGBA16_SNORM => F::Rgba16Snorm,
+            RGBA16_UINT => F::Rgba16Uint,
+            RGBA16_SINT => F::Rgba16Sint,
+            RGBA16_SFLOAT => F::Rgba16Float,
+            R32_UINT => F::R32Uint,
+            R32_SINT => F::R32Sint,
+            R32_SFLOAT => F::R32Float,
+            RG32_UINT => F::Rg32Uint,
+            RG32_SINT => F::Rg32Sint,
+            RG32_SFLOAT => F::Rg32Float,
+            RGB32_UINT | RGB32_SINT | RGB32_SFLOAT => {
+                return Err(FormatError {
+                    format: self,
+                    hint:
+                        "WebGPU has no three channel 32-bit formats, use the RGBA32 variant instead",
+                })
+            }
+            RGBA32_UINT => F::Rgba32Uint,
+            RGBA32_SINT => F::Rgba32Sint,
+            RGBA32_SFLOAT => F::Rgba32Float,
+            R10_G10_B10_A2_UNORM => F::Rgb10a2Unorm,
+            R10_G10_B10_A2_UINT => F::Rgb10a2Uint,
+            R11_G11_B10_UFLOAT => F::Rg11b10Ufloat,
+            R9_G9_B9_E5_UFLOAT => F::Rgb9e5Ufloat,
+        })
+    }
+
+    /// Device features needed to create a texture of this format with [`Format::to_wgpu`].
+    /// Using it as a storage texture may additionally require
+    /// `TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`.
+    #[cfg(feature = "wgpu-types")]
+    pub fn wgpu_features(self) -> wgpu_types::Features {
+        use Format::*;
+        match self {
+            R16_UNORM | R16_SNORM | RG16_UNORM | RG16_SNORM | RGBA16_UNORM | RGBA16_SNORM => {
+                wgpu_types::Features::TEXTURE_FORMAT_16BIT_NORM
+            }
+            _ => wgpu_types::Features::empty(),
+        }
+    }
+}
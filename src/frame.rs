@@ -0,0 +1,127 @@
+use std::time::Instant;
+
+use crate::ffi::{AccumulationMode, CommonSettings};
+use crate::math::{IntoMatrix4, IntoVector2};
+
+/// Camera state of a single frame.
+#[derive(Debug, Clone)]
+pub struct Camera {
+    // Column-major, non jittered
+    pub view_to_clip: [f32; 16],
+    pub world_to_view: [f32; 16],
+
+    // [-0.5; 0.5] - written into "CommonSettings::camera_jitter"
+    pub jitter: [f32; 2],
+
+    // (0; 1] - dynamic resolution scaling
+    pub resolution_scale: [f32; 2],
+}
+
+impl Camera {
+    pub fn new(view_to_clip: impl IntoMatrix4, world_to_view: impl IntoMatrix4) -> Self {
+        Self {
+            view_to_clip: view_to_clip.into_column_major(),
+            world_to_view: world_to_view.into_column_major(),
+            jitter: [0.0; 2],
+            resolution_scale: [1.0; 2],
+        }
+    }
+    pub fn with_jitter(mut self, jitter: impl IntoVector2) -> Self {
+        self.jitter = jitter.into_array();
+        self
+    }
+    pub fn with_resolution_scale(mut self, scale: impl IntoVector2) -> Self {
+        self.resolution_scale = scale.into_array();
+        self
+    }
+}
+
+/// Produces a [`CommonSettings`] every frame, carrying the current camera state over into the
+/// `*_prev` fields of the next frame.
+///
+/// The first frame and any frame following [`FrameState::restart`] or
+/// [`FrameState::clear_and_restart`] reset the accumulation and use the current camera as the
+/// previous one.
+pub struct FrameState {
+    settings: CommonSettings,
+    last_timestamp: Option<Instant>,
+    started: bool,
+    pending_reset: Option<AccumulationMode>,
+}
+
+impl FrameState {
+    /// `settings` provides the fields that are not tracked per frame, such as `denoising_range`.
+    pub fn new(settings: CommonSettings) -> Self {
+        Self {
+            settings,
+            last_timestamp: None,
+            started: false,
+            pending_reset: None,
+        }
+    }
+
+    /// The settings produced by the last call to [`FrameState::advance`].
+    pub fn settings(&self) -> &CommonSettings {
+        &self.settings
+    }
+
+    /// Access to the fields that are not tracked per frame. Camera fields, `frame_index`,
+    /// `time_delta_between_frames` and `accumulation_mode` are overwritten on the next frame.
+    pub fn settings_mut(&mut self) -> &mut CommonSettings {
+        &mut self.settings
+    }
+
+    /// Discards the history on the next frame, e.g. after a camera cut.
+    pub fn restart(&mut self) {
+        if self.pending_reset.is_none() {
+            self.pending_reset = Some(AccumulationMode::Restart);
+        }
+    }
+
+    /// Discards the history and clears the denoiser resources on the next frame.
+    pub fn clear_and_restart(&mut self) {
+        self.pending_reset = Some(AccumulationMode::ClearAndRestart);
+    }
+
+    pub fn advance(&mut self, camera: &Camera, timestamp: Instant) -> &CommonSettings {
+        let reset = if self.started {
+            self.pending_reset.take()
+        } else {
+            self.pending_reset = None;
+            Some(AccumulationMode::ClearAndRestart)
+        };
+        let settings = &mut self.settings;
+
+        if reset.is_some() {
+            settings.view_to_clip_matrix_prev = camera.view_to_clip;
+            settings.world_to_view_matrix_prev = camera.world_to_view;
+            settings.camera_jitter_prev = camera.jitter;
+            settings.resolution_scale_prev = camera.resolution_scale;
+            // Zero lets NRD track the frame time internally.
+            settings.time_delta_between_frames = 0.0;
+        } else {
+            settings.view_to_clip_matrix_prev = settings.view_to_clip_matrix;
+            settings.world_to_view_matrix_prev = settings.world_to_view_matrix;
+            settings.camera_jitter_prev = settings.camera_jitter;
+            settings.resolution_scale_prev = settings.resolution_scale;
+            settings.time_delta_between_frames = self
+                .last_timestamp
+                .map_or(0.0, |last| (timestamp - last).as_secs_f32() * 1000.0);
+        }
+        settings.view_to_clip_matrix = camera.view_to_clip;
+        settings.world_to_view_matrix = camera.world_to_view;
+        settings.camera_jitter = camera.jitter;
+        settings.resolution_scale = camera.resolution_scale;
+
+        settings.frame_index = if self.started {
+            settings.frame_index.wrapping_add(1)
+        } else {
+            0
+        };
+        settings.accumulation_mode = reset.unwrap_or(AccumulationMode::Continue);
+
+        self.started = true;
+        self.last_timestamp = Some(timestamp);
+        &self.settings
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::Write;
+
+use crate::ffi::{
+    self, DescriptorType, DispatchDesc, Identifier, InstanceDesc, ResourceType, TextureDesc,
+};
+use crate::Instance;
+
+/// A texture read or written by the dispatches of a frame.
+#[derive(Debug, Clone)]
+pub struct GraphResource {
+    pub ty: ResourceType,
+    // Only meaningful for `PERMANENT_POOL` and `TRANSIENT_POOL`
+    pub index_in_pool: u16,
+    // The pool entry, `None` for textures provided by the user
+    pub texture: Option<TextureDesc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphDispatch {
+    pub name: String,
+    pub pipeline_index: u16,
+    pub grid_width: u16,
+    pub grid_height: u16,
+}
+
+/// An edge between a dispatch and a resource, indices refer to [`DispatchGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphAccess {
+    pub dispatch: usize,
+    pub resource: usize,
+    pub write: bool,
+    pub mip_offset: u16,
+    pub mip_num: u16,
+}
+
+/// Data flow between the dispatches returned by `get_compute_dispatches` and the textures they
+/// use, for debugging and scheduling.
+#[derive(Debug, Clone, Default)]
+pub struct DispatchGraph {
+    pub dispatches: Vec<GraphDispatch>,
+    pub resources: Vec<GraphResource>,
+    pub accesses: Vec<GraphAccess>,
+}
+
+impl DispatchGraph {
+    pub fn new(desc: &InstanceDesc, dispatches: &[DispatchDesc]) -> Self {
+        Self::with_pools(desc.permanent_pool(), desc.transient_pool(), dispatches)
+    }
+
+    fn with_pools(
+        permanent_pool: &[TextureDesc],
+        transient_pool: &[TextureDesc],
+        dispatches: &[DispatchDesc],
+    ) -> Self {
+        let mut graph = Self::default();
+        for (dispatch_index, dispatch) in dispatches.iter().enumerate() {
+            graph.dispatches.push(GraphDispatch {
+                name: dispatch.name().to_string_lossy().into_owned(),
+                pipeline_index: dispatch.pipeline_index,
+                grid_width: dispatch.grid_width,
+                grid_height: dispatch.grid_height,
+            });
+            for resource in dispatch.resources() {
+                let pool = match resource.ty {
+                    ResourceType::PERMANENT_POOL => Some(permanent_pool),
+                    ResourceType::TRANSIENT_POOL => Some(transient_pool),
+                    _ => None,
+                };
+                let index_in_pool = if pool.is_some() {
+                    resource.index_in_pool
+                } else {
+                    0
+                };
+                let resource_index = graph
+                    .resources
+                    .iter()
+                    .position(|r| r.ty == resource.ty && r.index_in_pool == index_in_pool)
+                    .unwrap_or_else(|| {
+                        graph.resources.push(GraphResource {
+                            ty: resource.ty,
+                            index_in_pool,
+                            texture: pool
+                                .and_then(|pool| pool.get(index_in_pool as usize))
+                                .cloned(),
+                        });
+                        graph.resources.len() - 1
+                    });
+                graph.accesses.push(GraphAccess {
+                    dispatch: dispatch_index,
+                    resource: resource_index,
+                    write: resource.state_needed == DescriptorType::StorageTexture,
+                    mip_offset: resource.mip_offset,
+                    mip_num: resource.mip_num,
+                });
+            }
+        }
+        graph
+    }
+
+    /// Queries the dispatches of `identifiers` and builds their graph.
+    pub fn from_instance(
+        instance: &mut Instance,
+        identifiers: &[Identifier],
+    ) -> Result<Self, ffi::Result> {
+        // The query borrows the instance mutably, so the pools are copied first
+        let desc = instance.desc();
+        let permanent_pool = desc.permanent_pool().to_vec();
+        let transient_pool = desc.transient_pool().to_vec();
+        let dispatches = instance.get_compute_dispatches(identifiers)?;
+        Ok(Self::with_pools(
+            &permanent_pool,
+            &transient_pool,
+            dispatches,
+        ))
+    }
+
+    /// Graphviz source with boxes for dispatches and ellipses for textures. Edges point from the
+    /// textures a dispatch reads to the dispatch, and from the dispatch to the textures it writes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph nrd {\n    rankdir=LR;\n");
+        for (i, dispatch) in self.dispatches.iter().enumerate() {
+            let label = format!(
+                "{}\npipeline {}, {}x{}",
+                dispatch.name, dispatch.pipeline_index, dispatch.grid_width, dispatch.grid_height
+            );
+            writeln!(dot, "    d{i} [shape=box, label={}];", dot_string(&label)).unwrap();
+        }
+        for (i, resource) in self.resources.iter().enumerate() {
+            let label = match &resource.texture {
+                Some(texture) => format!(
+                    "{:?}[{}]\n{:?} {}x{}, {} mips",
+                    resource.ty,
+                    resource.index_in_pool,
+                    texture.format,
+                    texture.width,
+                    texture.height,
+                    texture.mip_num
+                ),
+                None => format!("{:?}", resource.ty),
+            };
+            writeln!(
+                dot,
+                "    r{i} [shape=ellipse, label={}];",
+                dot_string(&label)
+            )
+            .unwrap();
+        }
+        for access in &self.accesses {
+            let (from, to) = if access.write {
+                (
+                    format!("d{}", access.dispatch),
+                    format!("r{}", access.resource),
+                )
+            } else {
+                (
+                    format!("r{}", access.resource),
+                    format!("d{}", access.dispatch),
+                )
+            };
+            let label = if access.mip_num == 1 {
+                format!("mip {}", access.mip_offset)
+            } else {
+                format!(
+                    "mips {}..{}",
+                    access.mip_offset,
+                    access.mip_offset + access.mip_num
+                )
+            };
+            writeln!(dot, "    {from} -> {to} [label={}];", dot_string(&label)).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The graph as a JSON object with `dispatches`, `resources` and `accesses` arrays.
+    pub fn to_json(&self) -> String {
+        let dispatches: Vec<_> = self
+            .dispatches
+            .iter()
+            .map(|dispatch| {
+                format!(
+                    r#"{{"name":{},"pipeline_index":{},"grid_width":{},"grid_height":{}}}"#,
+                    json_string(&dispatch.name),
+                    dispatch.pipeline_index,
+                    dispatch.grid_width,
+                    dispatch.grid_height
+                )
+            })
+            .collect();
+        let resources: Vec<_> = self
+            .resources
+            .iter()
+            .map(|resource| match &resource.texture {
+                Some(texture) => format!(
+                    r#"{{"type":"{:?}","index_in_pool":{},"format":"{:?}","width":{},"height":{},"mip_num":{}}}"#,
+                    resource.ty,
+                    resource.index_in_pool,
+                    texture.format,
+                    texture.width,
+                    texture.height,
+                    texture.mip_num
+                ),
+                None => format!(r#"{{"type":"{:?}"}}"#, resource.ty),
+            })
+            .collect();
+        let accesses: Vec<_> = self
+            .accesses
+            .iter()
+            .map(|access| {
+                format!(
+                    r#"{{"dispatch":{},"resource":{},"write":{},"mip_offset":{},"mip_num":{}}}"#,
+                    access.dispatch,
+                    access.resource,
+                    access.write,
+                    access.mip_offset,
+                    access.mip_num
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"dispatches":[{}],"resources":[{}],"accesses":[{}]}}"#,
+            dispatches.join(","),
+            resources.join(","),
+            accesses.join(",")
+        )
+    }
+}
+
+fn dot_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,989 @@
+//! egui inspectors for the settings structs.
+//!
+//! Every `ui` method returns `true` if a value was changed this frame, so callers only forward
+//! settings to NRD when needed. Slider ranges cover the documented or practical range, values
+//! typed in outside of it are kept as-is and reported by [`crate::Validate`].
+
+use std::ops::RangeInclusive;
+
+use crate::ffi::{
+    AccumulationMode, CheckerboardMode, CommonSettings, HitDistanceParameters,
+    HitDistanceReconstructionMode, ReblurAntilagSettings, ReblurSettings, ReferenceSettings,
+    RelaxAntilagSettings, RelaxDiffuseSettings, RelaxDiffuseSpecularSettings,
+    RelaxSpecularSettings, SigmaSettings, REBLUR_MAX_HISTORY_FRAME_NUM,
+    RELAX_MAX_HISTORY_FRAME_NUM,
+};
+
+struct Inspector<'a> {
+    ui: &'a mut egui::Ui,
+    changed: bool,
+}
+
+impl<'a> Inspector<'a> {
+    fn new(ui: &'a mut egui::Ui) -> Self {
+        Self { ui, changed: false }
+    }
+
+    fn reset_button(&mut self) -> bool {
+        let clicked = self
+            .ui
+            .button("Reset to default")
+            .on_hover_text("Restore the NRD defaults for this section")
+            .clicked();
+        self.changed |= clicked;
+        clicked
+    }
+
+    fn reset<T: Default>(&mut self, value: &mut T) {
+        if self.reset_button() {
+            *value = T::default();
+        }
+    }
+
+    fn section(&mut self, title: &str, f: impl FnOnce(&mut Inspector)) {
+        let changed = egui::CollapsingHeader::new(title)
+            .show(self.ui, |ui| {
+                let mut inspector = Inspector::new(ui);
+                f(&mut inspector);
+                inspector.changed
+            })
+            .body_returned
+            .unwrap_or(false);
+        self.changed |= changed;
+    }
+
+    fn f32(&mut self, label: &str, value: &mut f32, range: RangeInclusive<f32>, tooltip: &str) {
+        let response = self.ui.add(
+            egui::Slider::new(value, range)
+                .text(label)
+                .clamping(egui::SliderClamping::Edits),
+        );
+        self.changed |= response.on_hover_text(tooltip).changed();
+    }
+
+    fn f32x2(
+        &mut self,
+        label: &str,
+        value: &mut [f32; 2],
+        range: RangeInclusive<f32>,
+        tooltip: &str,
+    ) {
+        self.f32(
+            &format!("{label}[0]"),
+            &mut value[0],
+            range.clone(),
+            tooltip,
+        );
+        self.f32(&format!("{label}[1]"), &mut value[1], range, tooltip);
+    }
+
+    fn u32(&mut self, label: &str, value: &mut u32, range: RangeInclusive<u32>, tooltip: &str) {
+        let response = self.ui.add(
+            egui::Slider::new(value, range)
+                .text(label)
+                .clamping(egui::SliderClamping::Edits),
+        );
+        self.changed |= response.on_hover_text(tooltip).changed();
+    }
+
+    fn bool(&mut self, label: &str, value: &mut bool, tooltip: &str) {
+        self.changed |= self
+            .ui
+            .checkbox(value, label)
+            .on_hover_text(tooltip)
+            .changed();
+    }
+
+    fn choice<T: PartialEq + Copy + std::fmt::Debug>(
+        &mut self,
+        label: &str,
+        value: &mut T,
+        options: &[T],
+        tooltip: &str,
+    ) {
+        let mut changed = false;
+        egui::ComboBox::from_label(label)
+            .selected_text(format!("{value:?}"))
+            .show_ui(self.ui, |ui| {
+                for &option in options {
+                    changed |= ui
+                        .selectable_value(value, option, format!("{option:?}"))
+                        .changed();
+                }
+            })
+            .response
+            .on_hover_text(tooltip);
+        self.changed |= changed;
+    }
+
+    fn checkerboard(&mut self, value: &mut CheckerboardMode) {
+        self.choice(
+            "checkerboard_mode",
+            value,
+            &[
+                CheckerboardMode::Off,
+                CheckerboardMode::Black,
+                CheckerboardMode::White,
+            ],
+            tooltip::CHECKERBOARD_MODE,
+        );
+    }
+
+    fn hit_distance_reconstruction(&mut self, value: &mut HitDistanceReconstructionMode) {
+        self.choice(
+            "hit_distance_reconstruction_mode",
+            value,
+            &[
+                HitDistanceReconstructionMode::Off,
+                HitDistanceReconstructionMode::Area3x3,
+                HitDistanceReconstructionMode::Area5x5,
+            ],
+            tooltip::HIT_DISTANCE_RECONSTRUCTION_MODE,
+        );
+    }
+}
+
+impl CommonSettings {
+    /// Inspects the fields that are usually tuned, not the per-frame camera state, which is also
+    /// left alone by the reset button.
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        if i.reset_button() {
+            let default = Self::default();
+            *self = Self {
+                view_to_clip_matrix: self.view_to_clip_matrix,
+                view_to_clip_matrix_prev: self.view_to_clip_matrix_prev,
+                world_to_view_matrix: self.world_to_view_matrix,
+                world_to_view_matrix_prev: self.world_to_view_matrix_prev,
+                world_prev_to_world_matrix: self.world_prev_to_world_matrix,
+                camera_jitter: self.camera_jitter,
+                camera_jitter_prev: self.camera_jitter_prev,
+                resolution_scale: self.resolution_scale,
+                resolution_scale_prev: self.resolution_scale_prev,
+                input_subrect_origin: self.input_subrect_origin,
+                frame_index: self.frame_index,
+                ..default
+            };
+        }
+        for (label, value) in ["x", "y", "z"].iter().zip(&mut self.motion_vector_scale) {
+            i.f32(
+                &format!("motion_vector_scale.{label}"),
+                value,
+                -2.0..=2.0,
+                tooltip::MOTION_VECTOR_SCALE,
+            );
+        }
+        i.f32(
+            "time_delta_between_frames",
+            &mut self.time_delta_between_frames,
+            0.0..=100.0,
+            tooltip::TIME_DELTA_BETWEEN_FRAMES,
+        );
+        i.f32(
+            "denoising_range",
+            &mut self.denoising_range,
+            1.0..=524031.0,
+            tooltip::DENOISING_RANGE,
+        );
+        i.f32(
+            "disocclusion_threshold",
+            &mut self.disocclusion_threshold,
+            0.0..=0.05,
+            tooltip::DISOCCLUSION_THRESHOLD,
+        );
+        i.f32(
+            "disocclusion_threshold_alternate",
+            &mut self.disocclusion_threshold_alternate,
+            0.0..=0.2,
+            tooltip::DISOCCLUSION_THRESHOLD_ALTERNATE,
+        );
+        i.f32(
+            "split_screen",
+            &mut self.split_screen,
+            0.0..=1.0,
+            tooltip::SPLIT_SCREEN,
+        );
+        i.f32("debug", &mut self.debug, 0.0..=1.0, tooltip::DEBUG);
+        i.choice(
+            "accumulation_mode",
+            &mut self.accumulation_mode,
+            &[
+                AccumulationMode::Continue,
+                AccumulationMode::Restart,
+                AccumulationMode::ClearAndRestart,
+            ],
+            tooltip::ACCUMULATION_MODE,
+        );
+        i.bool(
+            "is_motion_vector_in_world_space",
+            &mut self.is_motion_vector_in_world_space,
+            tooltip::IS_MOTION_VECTOR_IN_WORLD_SPACE,
+        );
+        i.bool(
+            "is_history_confidence_available",
+            &mut self.is_history_confidence_available,
+            tooltip::IS_HISTORY_CONFIDENCE_AVAILABLE,
+        );
+        i.bool(
+            "is_disocclusion_threshold_mix_available",
+            &mut self.is_disocclusion_threshold_mix_available,
+            tooltip::IS_DISOCCLUSION_THRESHOLD_MIX_AVAILABLE,
+        );
+        i.bool(
+            "is_base_color_metalness_available",
+            &mut self.is_base_color_metalness_available,
+            tooltip::IS_BASE_COLOR_METALNESS_AVAILABLE,
+        );
+        i.bool(
+            "enable_validation",
+            &mut self.enable_validation,
+            tooltip::ENABLE_VALIDATION,
+        );
+        i.changed
+    }
+}
+
+impl HitDistanceParameters {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.f32("a", &mut self.a, 0.0..=100.0, tooltip::HIT_DISTANCE_A);
+        i.f32("b", &mut self.b, 0.001..=1.0, tooltip::HIT_DISTANCE_B);
+        i.f32("c", &mut self.c, 1.0..=100.0, tooltip::HIT_DISTANCE_C);
+        i.f32("d", &mut self.d, -50.0..=0.0, tooltip::HIT_DISTANCE_D);
+        i.changed
+    }
+}
+
+impl ReblurAntilagSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.f32(
+            "luminance_sigma_scale",
+            &mut self.luminance_sigma_scale,
+            1.0..=3.0,
+            tooltip::REBLUR_ANTILAG_SIGMA_SCALE,
+        );
+        i.f32(
+            "hit_distance_sigma_scale",
+            &mut self.hit_distance_sigma_scale,
+            1.0..=3.0,
+            tooltip::REBLUR_ANTILAG_SIGMA_SCALE,
+        );
+        i.f32(
+            "luminance_antilag_power",
+            &mut self.luminance_antilag_power,
+            0.01..=1.0,
+            tooltip::REBLUR_ANTILAG_POWER,
+        );
+        i.f32(
+            "hit_distance_antilag_power",
+            &mut self.hit_distance_antilag_power,
+            0.01..=1.0,
+            tooltip::REBLUR_ANTILAG_POWER,
+        );
+        i.changed
+    }
+}
+
+impl ReblurSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.section("Hit distance parameters", |i| {
+            i.changed |= self.hit_distance_parameters.ui(i.ui);
+        });
+        i.section("Antilag", |i| {
+            i.changed |= self.antilag_settings.ui(i.ui);
+        });
+        i.u32(
+            "max_accumulated_frame_num",
+            &mut self.max_accumulated_frame_num,
+            0..=REBLUR_MAX_HISTORY_FRAME_NUM,
+            tooltip::REBLUR_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "max_fast_accumulated_frame_num",
+            &mut self.max_fast_accumulated_frame_num,
+            0..=REBLUR_MAX_HISTORY_FRAME_NUM,
+            tooltip::REBLUR_MAX_FAST_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "history_fix_frame_num",
+            &mut self.history_fix_frame_num,
+            0..=REBLUR_MAX_HISTORY_FRAME_NUM,
+            tooltip::REBLUR_HISTORY_FIX_FRAME_NUM,
+        );
+        i.f32(
+            "diffuse_prepass_blur_radius",
+            &mut self.diffuse_prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::REBLUR_PREPASS_BLUR_RADIUS,
+        );
+        i.f32(
+            "specular_prepass_blur_radius",
+            &mut self.specular_prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::REBLUR_PREPASS_BLUR_RADIUS,
+        );
+        i.f32(
+            "blur_radius",
+            &mut self.blur_radius,
+            0.0..=60.0,
+            tooltip::BLUR_RADIUS,
+        );
+        i.f32(
+            "history_fix_stride_between_samples",
+            &mut self.history_fix_stride_between_samples,
+            0.0..=30.0,
+            tooltip::HISTORY_FIX_STRIDE_BETWEEN_SAMPLES,
+        );
+        i.f32(
+            "lobe_angle_fraction",
+            &mut self.lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        i.f32(
+            "roughness_fraction",
+            &mut self.roughness_fraction,
+            0.0..=1.0,
+            tooltip::ROUGHNESS_FRACTION,
+        );
+        i.f32(
+            "responsive_accumulation_roughness_threshold",
+            &mut self.responsive_accumulation_roughness_threshold,
+            0.0..=1.0,
+            tooltip::RESPONSIVE_ACCUMULATION_ROUGHNESS_THRESHOLD,
+        );
+        i.f32(
+            "stabilization_strength",
+            &mut self.stabilization_strength,
+            0.0..=1.0,
+            tooltip::STABILIZATION_STRENGTH,
+        );
+        i.f32(
+            "plane_distance_sensitivity",
+            &mut self.plane_distance_sensitivity,
+            0.0..=0.05,
+            tooltip::PLANE_DISTANCE_SENSITIVITY,
+        );
+        i.f32x2(
+            "specular_probability_thresholds_for_mv_modification",
+            &mut self.specular_probability_thresholds_for_mv_modification,
+            0.0..=1.0,
+            tooltip::SPECULAR_PROBABILITY_THRESHOLDS_FOR_MV_MODIFICATION,
+        );
+        i.checkerboard(&mut self.checkerboard_mode);
+        i.hit_distance_reconstruction(&mut self.hit_distance_reconstruction_mode);
+        i.bool(
+            "enable_anti_firefly",
+            &mut self.enable_anti_firefly,
+            tooltip::REBLUR_ENABLE_ANTI_FIREFLY,
+        );
+        i.bool(
+            "enable_reference_accumulation",
+            &mut self.enable_reference_accumulation,
+            tooltip::ENABLE_REFERENCE_ACCUMULATION,
+        );
+        i.bool(
+            "enable_performance_mode",
+            &mut self.enable_performance_mode,
+            tooltip::ENABLE_PERFORMANCE_MODE,
+        );
+        i.bool(
+            "enable_material_test_for_diffuse",
+            &mut self.enable_material_test_for_diffuse,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.bool(
+            "enable_material_test_for_specular",
+            &mut self.enable_material_test_for_specular,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.bool(
+            "use_prepass_only_for_specular_motion_estimation",
+            &mut self.use_prepass_only_for_specular_motion_estimation,
+            tooltip::USE_PREPASS_ONLY_FOR_SPECULAR_MOTION_ESTIMATION,
+        );
+        i.changed
+    }
+}
+
+impl SigmaSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.f32(
+            "plane_distance_sensitivity",
+            &mut self.plane_distance_sensitivity,
+            0.0..=0.05,
+            tooltip::PLANE_DISTANCE_SENSITIVITY,
+        );
+        i.f32(
+            "blur_radius_scale",
+            &mut self.blur_radius_scale,
+            1.0..=3.0,
+            tooltip::BLUR_RADIUS_SCALE,
+        );
+        i.changed
+    }
+}
+
+impl ReferenceSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.u32(
+            "max_accumulated_frame_num",
+            &mut self.max_accumulated_frame_num,
+            0..=4096,
+            tooltip::REFERENCE_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.changed
+    }
+}
+
+impl RelaxAntilagSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.f32(
+            "acceleration_amount",
+            &mut self.acceleration_amount,
+            0.0..=1.0,
+            tooltip::ACCELERATION_AMOUNT,
+        );
+        i.f32(
+            "spatial_sigma_scale",
+            &mut self.spatial_sigma_scale,
+            0.0..=10.0,
+            tooltip::RELAX_ANTILAG_SIGMA_SCALE,
+        );
+        i.f32(
+            "temporal_sigma_scale",
+            &mut self.temporal_sigma_scale,
+            0.0..=10.0,
+            tooltip::RELAX_ANTILAG_SIGMA_SCALE,
+        );
+        i.f32(
+            "reset_amount",
+            &mut self.reset_amount,
+            0.0..=1.0,
+            tooltip::RESET_AMOUNT,
+        );
+        i.changed
+    }
+}
+
+// Fields shared by the three RELAX settings structs, in declaration order
+macro_rules! relax_common_ui {
+    ($i: ident, $settings: expr) => {
+        $i.f32(
+            "history_fix_edge_stopping_normal_power",
+            &mut $settings.history_fix_edge_stopping_normal_power,
+            0.0..=16.0,
+            tooltip::HISTORY_FIX_EDGE_STOPPING_NORMAL_POWER,
+        );
+        $i.f32(
+            "history_fix_stride_between_samples",
+            &mut $settings.history_fix_stride_between_samples,
+            0.0..=30.0,
+            tooltip::HISTORY_FIX_STRIDE_BETWEEN_SAMPLES,
+        );
+        $i.f32(
+            "history_clamping_color_box_sigma_scale",
+            &mut $settings.history_clamping_color_box_sigma_scale,
+            1.0..=3.0,
+            tooltip::HISTORY_CLAMPING_COLOR_BOX_SIGMA_SCALE,
+        );
+        $i.u32(
+            "spatial_variance_estimation_history_threshold",
+            &mut $settings.spatial_variance_estimation_history_threshold,
+            0..=10,
+            tooltip::SPATIAL_VARIANCE_ESTIMATION_HISTORY_THRESHOLD,
+        );
+        $i.u32(
+            "atrous_iteration_num",
+            &mut $settings.atrous_iteration_num,
+            2..=8,
+            tooltip::ATROUS_ITERATION_NUM,
+        );
+    };
+}
+
+macro_rules! relax_confidence_ui {
+    ($i: ident, $settings: expr) => {
+        $i.f32(
+            "depth_threshold",
+            &mut $settings.depth_threshold,
+            0.0..=0.05,
+            tooltip::DEPTH_THRESHOLD,
+        );
+        $i.f32(
+            "confidence_driven_relaxation_multiplier",
+            &mut $settings.confidence_driven_relaxation_multiplier,
+            0.0..=1.0,
+            tooltip::CONFIDENCE_DRIVEN_RELAXATION,
+        );
+        $i.f32(
+            "confidence_driven_luminance_edge_stopping_relaxation",
+            &mut $settings.confidence_driven_luminance_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::CONFIDENCE_DRIVEN_RELAXATION,
+        );
+        $i.f32(
+            "confidence_driven_normal_edge_stopping_relaxation",
+            &mut $settings.confidence_driven_normal_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::CONFIDENCE_DRIVEN_RELAXATION,
+        );
+    };
+}
+
+impl RelaxDiffuseSpecularSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.section("Antilag", |i| {
+            i.changed |= self.antilag_settings.ui(i.ui);
+        });
+        i.f32(
+            "diffuse_prepass_blur_radius",
+            &mut self.diffuse_prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::RELAX_PREPASS_BLUR_RADIUS,
+        );
+        i.f32(
+            "specular_prepass_blur_radius",
+            &mut self.specular_prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::RELAX_PREPASS_BLUR_RADIUS,
+        );
+        i.u32(
+            "diffuse_max_accumulated_frame_num",
+            &mut self.diffuse_max_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "specular_max_accumulated_frame_num",
+            &mut self.specular_max_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "diffuse_max_fast_accumulated_frame_num",
+            &mut self.diffuse_max_fast_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_FAST_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "specular_max_fast_accumulated_frame_num",
+            &mut self.specular_max_fast_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_FAST_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "history_fix_frame_num",
+            &mut self.history_fix_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_HISTORY_FIX_FRAME_NUM,
+        );
+        i.f32(
+            "diffuse_phi_luminance",
+            &mut self.diffuse_phi_luminance,
+            0.0..=10.0,
+            tooltip::PHI_LUMINANCE,
+        );
+        i.f32(
+            "specular_phi_luminance",
+            &mut self.specular_phi_luminance,
+            0.0..=10.0,
+            tooltip::PHI_LUMINANCE,
+        );
+        i.f32(
+            "diffuse_lobe_angle_fraction",
+            &mut self.diffuse_lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        i.f32(
+            "specular_lobe_angle_fraction",
+            &mut self.specular_lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        i.f32(
+            "roughness_fraction",
+            &mut self.roughness_fraction,
+            0.0..=1.0,
+            tooltip::ROUGHNESS_FRACTION,
+        );
+        i.f32(
+            "specular_variance_boost",
+            &mut self.specular_variance_boost,
+            0.0..=8.0,
+            tooltip::SPECULAR_VARIANCE_BOOST,
+        );
+        i.f32(
+            "specular_lobe_angle_slack",
+            &mut self.specular_lobe_angle_slack,
+            0.0..=1.0,
+            tooltip::SPECULAR_LOBE_ANGLE_SLACK,
+        );
+        relax_common_ui!(i, self);
+        i.f32(
+            "diffuse_min_luminance_weight",
+            &mut self.diffuse_min_luminance_weight,
+            0.0..=1.0,
+            tooltip::MIN_LUMINANCE_WEIGHT,
+        );
+        i.f32(
+            "specular_min_luminance_weight",
+            &mut self.specular_min_luminance_weight,
+            0.0..=1.0,
+            tooltip::MIN_LUMINANCE_WEIGHT,
+        );
+        relax_confidence_ui!(i, self);
+        i.f32(
+            "luminance_edge_stopping_relaxation",
+            &mut self.luminance_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::EDGE_STOPPING_RELAXATION,
+        );
+        i.f32(
+            "normal_edge_stopping_relaxation",
+            &mut self.normal_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::EDGE_STOPPING_RELAXATION,
+        );
+        i.f32(
+            "roughness_edge_stopping_relaxation",
+            &mut self.roughness_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::ROUGHNESS_EDGE_STOPPING_RELAXATION,
+        );
+        i.checkerboard(&mut self.checkerboard_mode);
+        i.hit_distance_reconstruction(&mut self.hit_distance_reconstruction_mode);
+        i.bool(
+            "enable_anti_firefly",
+            &mut self.enable_anti_firefly,
+            tooltip::RELAX_ENABLE_ANTI_FIREFLY,
+        );
+        i.bool(
+            "enable_reprojection_test_skipping_without_motion",
+            &mut self.enable_reprojection_test_skipping_without_motion,
+            tooltip::ENABLE_REPROJECTION_TEST_SKIPPING_WITHOUT_MOTION,
+        );
+        i.bool(
+            "enable_roughness_edge_stopping",
+            &mut self.enable_roughness_edge_stopping,
+            tooltip::ENABLE_ROUGHNESS_EDGE_STOPPING,
+        );
+        i.bool(
+            "enable_material_test_for_diffuse",
+            &mut self.enable_material_test_for_diffuse,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.bool(
+            "enable_material_test_for_specular",
+            &mut self.enable_material_test_for_specular,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.changed
+    }
+}
+
+impl RelaxDiffuseSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.section("Antilag", |i| {
+            i.changed |= self.antilag_settings.ui(i.ui);
+        });
+        i.f32(
+            "prepass_blur_radius",
+            &mut self.prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::RELAX_PREPASS_BLUR_RADIUS,
+        );
+        i.u32(
+            "diffuse_max_accumulated_frame_num",
+            &mut self.diffuse_max_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "diffuse_max_fast_accumulated_frame_num",
+            &mut self.diffuse_max_fast_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_FAST_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "history_fix_frame_num",
+            &mut self.history_fix_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_HISTORY_FIX_FRAME_NUM,
+        );
+        i.f32(
+            "diffuse_phi_luminance",
+            &mut self.diffuse_phi_luminance,
+            0.0..=10.0,
+            tooltip::PHI_LUMINANCE,
+        );
+        i.f32(
+            "diffuse_lobe_angle_fraction",
+            &mut self.diffuse_lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        relax_common_ui!(i, self);
+        i.f32(
+            "min_luminance_weight",
+            &mut self.min_luminance_weight,
+            0.0..=1.0,
+            tooltip::MIN_LUMINANCE_WEIGHT,
+        );
+        relax_confidence_ui!(i, self);
+        i.checkerboard(&mut self.checkerboard_mode);
+        i.hit_distance_reconstruction(&mut self.hit_distance_reconstruction_mode);
+        i.bool(
+            "enable_anti_firefly",
+            &mut self.enable_anti_firefly,
+            tooltip::RELAX_ENABLE_ANTI_FIREFLY,
+        );
+        i.bool(
+            "enable_reprojection_test_skipping_without_motion",
+            &mut self.enable_reprojection_test_skipping_without_motion,
+            tooltip::ENABLE_REPROJECTION_TEST_SKIPPING_WITHOUT_MOTION,
+        );
+        i.bool(
+            "enable_material_test",
+            &mut self.enable_material_test,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.changed
+    }
+}
+
+impl RelaxSpecularSettings {
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut i = Inspector::new(ui);
+        i.reset(self);
+        i.section("Antilag", |i| {
+            i.changed |= self.antilag_settings.ui(i.ui);
+        });
+        i.f32(
+            "prepass_blur_radius",
+            &mut self.prepass_blur_radius,
+            0.0..=100.0,
+            tooltip::RELAX_PREPASS_BLUR_RADIUS,
+        );
+        i.u32(
+            "specular_max_accumulated_frame_num",
+            &mut self.specular_max_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "specular_max_fast_accumulated_frame_num",
+            &mut self.specular_max_fast_accumulated_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_MAX_FAST_ACCUMULATED_FRAME_NUM,
+        );
+        i.u32(
+            "history_fix_frame_num",
+            &mut self.history_fix_frame_num,
+            0..=RELAX_MAX_HISTORY_FRAME_NUM,
+            tooltip::RELAX_HISTORY_FIX_FRAME_NUM,
+        );
+        i.f32(
+            "specular_phi_luminance",
+            &mut self.specular_phi_luminance,
+            0.0..=10.0,
+            tooltip::PHI_LUMINANCE,
+        );
+        i.f32(
+            "diffuse_lobe_angle_fraction",
+            &mut self.diffuse_lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        i.f32(
+            "specular_lobe_angle_fraction",
+            &mut self.specular_lobe_angle_fraction,
+            0.0..=1.0,
+            tooltip::LOBE_ANGLE_FRACTION,
+        );
+        i.f32(
+            "roughness_fraction",
+            &mut self.roughness_fraction,
+            0.0..=1.0,
+            tooltip::ROUGHNESS_FRACTION,
+        );
+        i.f32(
+            "specular_variance_boost",
+            &mut self.specular_variance_boost,
+            0.0..=8.0,
+            tooltip::SPECULAR_VARIANCE_BOOST,
+        );
+        i.f32(
+            "specular_lobe_angle_slack",
+            &mut self.specular_lobe_angle_slack,
+            0.0..=1.0,
+            tooltip::SPECULAR_LOBE_ANGLE_SLACK,
+        );
+        relax_common_ui!(i, self);
+        i.f32(
+            "min_luminance_weight",
+            &mut self.min_luminance_weight,
+            0.0..=1.0,
+            tooltip::MIN_LUMINANCE_WEIGHT,
+        );
+        relax_confidence_ui!(i, self);
+        i.f32(
+            "luminance_edge_stopping_relaxation",
+            &mut self.luminance_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::EDGE_STOPPING_RELAXATION,
+        );
+        i.f32(
+            "normal_edge_stopping_relaxation",
+            &mut self.normal_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::EDGE_STOPPING_RELAXATION,
+        );
+        i.f32(
+            "roughness_edge_stopping_relaxation",
+            &mut self.roughness_edge_stopping_relaxation,
+            0.0..=1.0,
+            tooltip::ROUGHNESS_EDGE_STOPPING_RELAXATION,
+        );
+        i.checkerboard(&mut self.checkerboard_mode);
+        i.hit_distance_reconstruction(&mut self.hit_distance_reconstruction_mode);
+        i.bool(
+            "enable_anti_firefly",
+            &mut self.enable_anti_firefly,
+            tooltip::RELAX_ENABLE_ANTI_FIREFLY,
+        );
+        i.bool(
+            "enable_reprojection_test_skipping_without_motion",
+            &mut self.enable_reprojection_test_skipping_without_motion,
+            tooltip::ENABLE_REPROJECTION_TEST_SKIPPING_WITHOUT_MOTION,
+        );
+        i.bool(
+            "enable_roughness_edge_stopping",
+            &mut self.enable_roughness_edge_stopping,
+            tooltip::ENABLE_ROUGHNESS_EDGE_STOPPING,
+        );
+        i.bool(
+            "enable_material_test",
+            &mut self.enable_material_test,
+            tooltip::ENABLE_MATERIAL_TEST,
+        );
+        i.changed
+    }
+}
+
+// The header comments of the settings fields, shared by every field they document
+mod tooltip {
+    // CommonSettings
+    pub const MOTION_VECTOR_SCALE: &str =
+        "used as \"IN_MV * motionVectorScale\" (use .z = 0 for 2D screen-space motion)";
+    pub const TIME_DELTA_BETWEEN_FRAMES: &str =
+        "(ms) - user provided if > 0, otherwise - tracked internally";
+    pub const DENOISING_RANGE: &str = "(units) > 0 - use TLAS or tracing range (max value = NRD_FP16_MAX / NRD_FP16_VIEWZ_SCALE - 1 = 524031)";
+    pub const DISOCCLUSION_THRESHOLD: &str = "(normalized %) - if relative distance difference is greater than threshold, history gets reset (0.5-2.5% works well)";
+    pub const DISOCCLUSION_THRESHOLD_ALTERNATE: &str = "(normalized %) - alternative disocclusion threshold, which is mixed to based on IN_DISOCCLUSION_THRESHOLD_MIX";
+    pub const SPLIT_SCREEN: &str = "[0; 1] - enables \"noisy input / denoised output\" comparison";
+    pub const DEBUG: &str = "For internal needs";
+    pub const ACCUMULATION_MODE: &str =
+        "To reset history set to RESTART / CLEAR_AND_RESTART for one frame";
+    pub const IS_MOTION_VECTOR_IN_WORLD_SPACE: &str = "If \"true\" IN_MV is 3D motion in world-space (0 should be everywhere if the scene is static), otherwise it's 2D (+ optional Z delta) screen-space motion (0 should be everywhere if the camera doesn't move) (recommended value = true)";
+    pub const IS_HISTORY_CONFIDENCE_AVAILABLE: &str =
+        "If \"true\" IN_DIFF_CONFIDENCE and IN_SPEC_CONFIDENCE are available";
+    pub const IS_DISOCCLUSION_THRESHOLD_MIX_AVAILABLE: &str =
+        "If \"true\" IN_DISOCCLUSION_THRESHOLD_MIX is available";
+    pub const IS_BASE_COLOR_METALNESS_AVAILABLE: &str =
+        "If \"true\" IN_BASECOLOR_METALNESS is available";
+    pub const ENABLE_VALIDATION: &str = "Enables debug overlay in OUT_VALIDATION, requires \"InstanceCreationDesc::allowValidation = true\"";
+
+    // HitDistanceParameters
+    pub const HIT_DISTANCE_A: &str = "(units) - constant value IMPORTANT: if your unit is not \"meter\", you must convert it from \"meters\" to \"units\" manually!";
+    pub const HIT_DISTANCE_B: &str =
+        "(> 0) - viewZ based linear scale (1 m - 10 cm, 10 m - 1 m, 100 m - 10 m)";
+    pub const HIT_DISTANCE_C: &str = "(>= 1) - roughness based scale, use values > 1 to get bigger hit distance for low roughness";
+    pub const HIT_DISTANCE_D: &str = "(<= 0) - absolute value should be big enough to collapse \"exp2( D * roughness ^ 2 )\" to \"~0\" for roughness = 1";
+
+    // ReblurAntilagSettings
+    pub const REBLUR_ANTILAG_SIGMA_SCALE: &str =
+        "[1; 3] - delta is reduced by local variance multiplied by this value";
+    pub const REBLUR_ANTILAG_POWER: &str = "(0; 1] - antilag = pow( antilag, power )";
+
+    // ReblurSettings
+    pub const REBLUR_MAX_ACCUMULATED_FRAME_NUM: &str = "[0; REBLUR_MAX_HISTORY_FRAME_NUM] - maximum number of linearly accumulated frames (= FPS * \"time of accumulation\")";
+    pub const REBLUR_MAX_FAST_ACCUMULATED_FRAME_NUM: &str = "[0; REBLUR_MAX_HISTORY_FRAME_NUM] - maximum number of linearly accumulated frames in fast history (less than \"maxAccumulatedFrameNum\")";
+    pub const REBLUR_HISTORY_FIX_FRAME_NUM: &str = "[0; REBLUR_MAX_HISTORY_FRAME_NUM] - number of reconstructed frames after history reset (less than \"maxFastAccumulatedFrameNum\")";
+    pub const REBLUR_PREPASS_BLUR_RADIUS: &str = "(pixels) - pre-accumulation spatial reuse pass blur radius (0 = disabled, recommended in case of probabilistic sampling)";
+    pub const BLUR_RADIUS: &str = "(pixels) - base denoising radius (30 is a baseline for 1440p)";
+    pub const HISTORY_FIX_STRIDE_BETWEEN_SAMPLES: &str =
+        "(pixels) - base stride between samples in history reconstruction pass";
+    pub const LOBE_ANGLE_FRACTION: &str = "(normalized %) - base fraction of diffuse or specular lobe angle used to drive normal based rejection";
+    pub const ROUGHNESS_FRACTION: &str = "(normalized %) - base fraction of center roughness used to drive roughness based rejection";
+    pub const RESPONSIVE_ACCUMULATION_ROUGHNESS_THRESHOLD: &str = "[0; 1] - if roughness < this, temporal accumulation becomes responsive and driven by roughness (useful for animated water)";
+    pub const STABILIZATION_STRENGTH: &str = "(normalized %) - stabilizes output, more stabilization improves antilag (clean signals can use lower values)";
+    pub const PLANE_DISTANCE_SENSITIVITY: &str =
+        "(normalized %) - represents maximum allowed deviation from local tangent plane";
+    pub const SPECULAR_PROBABILITY_THRESHOLDS_FOR_MV_MODIFICATION: &str =
+        "IN_MV = lerp(IN_MV, specularMotion, smoothstep(this[0], this[1], specularProbability))";
+    pub const CHECKERBOARD_MODE: &str = "If not OFF and used for DIFFUSE_SPECULAR, defines diffuse orientation, specular orientation is the opposite";
+    pub const HIT_DISTANCE_RECONSTRUCTION_MODE: &str = "Must be used only in case of probabilistic sampling (not checkerboarding), when a pixel can be skipped and have \"0\" (invalid) hit distance";
+    pub const REBLUR_ENABLE_ANTI_FIREFLY: &str =
+        "Adds bias in case of badly defined signals, but tries to fight with fireflies";
+    pub const ENABLE_REFERENCE_ACCUMULATION: &str =
+        "Turns off spatial filtering and virtual motion based specular tracking";
+    pub const ENABLE_PERFORMANCE_MODE: &str = "Boosts performance by sacrificing IQ";
+    pub const ENABLE_MATERIAL_TEST: &str = "Spatial passes do optional material index comparison as: ( materialEnabled ? material[ center ] == material[ sample ] : 1 )";
+    pub const USE_PREPASS_ONLY_FOR_SPECULAR_MOTION_ESTIMATION: &str = "In rare cases, when bright samples are so sparse that any other bright neighbor can't be reached, pre-pass transforms a standalone bright pixel into a standalone bright blob, worsening the situation. Despite that it's a problem of sampling, the denoiser needs to handle it somehow on its side too. Diffuse pre-pass can be just disabled, but for specular it's still needed to find optimal hit distance for tracking. This boolean allow to use specular pre-pass for tracking purposes only";
+
+    // SigmaSettings
+    pub const BLUR_RADIUS_SCALE: &str = "[1; 3] - adds bias and stability if > 1";
+
+    // ReferenceSettings
+    pub const REFERENCE_MAX_ACCUMULATED_FRAME_NUM: &str = "(>= 0) - maximum number of linearly accumulated frames ( = FPS * \"time of accumulation\")";
+
+    // RelaxAntilagSettings
+    pub const ACCELERATION_AMOUNT: &str =
+        "[0; 1] - amount of history acceleration if history clamping happened in pixel";
+    pub const RELAX_ANTILAG_SIGMA_SCALE: &str = "(> 0) - history is being reset if delta between history and raw input is larger than spatial sigma + temporal sigma";
+    pub const RESET_AMOUNT: &str =
+        "[0; 1] - amount of history reset, 0.0 - no reset, 1.0 - full reset";
+
+    // RelaxDiffuseSpecularSettings, also used for RelaxDiffuseSettings and RelaxSpecularSettings
+    pub const RELAX_PREPASS_BLUR_RADIUS: &str = "(pixels) - pre-accumulation spatial reuse pass blur radius (0 = disabled, must be used in case of probabilistic sampling)";
+    pub const RELAX_MAX_ACCUMULATED_FRAME_NUM: &str = "[0; RELAX_MAX_HISTORY_FRAME_NUM] - maximum number of linearly accumulated frames ( = FPS * \"time of accumulation\")";
+    pub const RELAX_MAX_FAST_ACCUMULATED_FRAME_NUM: &str = "[0; RELAX_MAX_HISTORY_FRAME_NUM] - maximum number of linearly accumulated frames in fast history (less than \"maxAccumulatedFrameNum\")";
+    pub const RELAX_HISTORY_FIX_FRAME_NUM: &str = "[0; RELAX_MAX_HISTORY_FRAME_NUM] - number of reconstructed frames after history reset (less than \"maxFastAccumulatedFrameNum\")";
+    pub const PHI_LUMINANCE: &str = "A-trous edge stopping Luminance sensitivity";
+    pub const SPECULAR_VARIANCE_BOOST: &str =
+        "(>= 0) - how much variance we inject to specular if reprojection confidence is low";
+    pub const SPECULAR_LOBE_ANGLE_SLACK: &str = "(degrees) - slack for the specular lobe angle used in normal based rejection of specular during A-Trous passes";
+    pub const HISTORY_FIX_EDGE_STOPPING_NORMAL_POWER: &str =
+        "(> 0) - normal edge stopper for history reconstruction pass";
+    pub const HISTORY_CLAMPING_COLOR_BOX_SIGMA_SCALE: &str = "[1; 3] - standard deviation scale of color box for clamping main \"slow\" history to responsive \"fast\" history";
+    pub const SPATIAL_VARIANCE_ESTIMATION_HISTORY_THRESHOLD: &str = "(>= 0) - history length threshold below which spatial variance estimation will be executed";
+    pub const ATROUS_ITERATION_NUM: &str =
+        "[2; 8] - number of iterations for A-Trous wavelet transform";
+    pub const MIN_LUMINANCE_WEIGHT: &str =
+        "[0; 1] - A-trous edge stopping Luminance weight minimum";
+    pub const DEPTH_THRESHOLD: &str = "(normalized %) - Depth threshold for spatial passes";
+    pub const CONFIDENCE_DRIVEN_RELAXATION: &str = "Confidence inputs can affect spatial blurs, relaxing some weights in areas with low confidence";
+    pub const EDGE_STOPPING_RELAXATION: &str = "How much we relax roughness based rejection for spatial filter in areas where specular reprojection is low";
+    pub const ROUGHNESS_EDGE_STOPPING_RELAXATION: &str =
+        "How much we relax rejection for spatial filter based on roughness and view vector";
+    pub const RELAX_ENABLE_ANTI_FIREFLY: &str = "Firefly suppression";
+    pub const ENABLE_REPROJECTION_TEST_SKIPPING_WITHOUT_MOTION: &str = "Skip reprojection test when there is no motion, might improve quality along the edges for static camera with a jitter";
+    pub const ENABLE_ROUGHNESS_EDGE_STOPPING: &str = "Roughness based rejection";
+}
@@ -0,0 +1,123 @@
+/// Low discrepancy sequences used to jitter the projection matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JitterPattern {
+    // Halton sequence with base 2 for X and base 3 for Y
+    Halton23,
+
+    // Martin Roberts' R2 sequence, based on the plastic number
+    R2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jitter {
+    // [-0.5; 0.5] - pixel offset, value for "CommonSettings::camera_jitter"
+    pub camera_jitter: [f32; 2],
+
+    // Offset in NDC units (+Y down, as in Vulkan) to add to the translation of the projection
+    pub projection_offset: [f32; 2],
+}
+
+impl Jitter {
+    pub fn new(camera_jitter: [f32; 2], render_width: u32, render_height: u32) -> Self {
+        Self {
+            camera_jitter,
+            projection_offset: [
+                2.0 * camera_jitter[0] / render_width as f32,
+                2.0 * camera_jitter[1] / render_height as f32,
+            ],
+        }
+    }
+}
+
+/// Cycles through `phase_count` samples of a [`JitterPattern`].
+#[derive(Debug, Clone)]
+pub struct JitterSequence {
+    pattern: JitterPattern,
+    phase_count: u32,
+    index: u32,
+}
+
+impl JitterSequence {
+    pub fn new(pattern: JitterPattern, phase_count: u32) -> Self {
+        assert!(phase_count > 0, "phase count must be positive");
+        Self {
+            pattern,
+            phase_count,
+            index: 0,
+        }
+    }
+
+    /// Scales `base_phase_count` by the number of display pixels covered by a render pixel, so
+    /// that every display pixel receives the same number of samples when upscaling.
+    ///
+    /// Panics if a scale is not positive and finite. Scales below `1 / base_phase_count` in area
+    /// are clamped, which caps the phase count at `base_phase_count²`.
+    pub fn with_resolution_scale(
+        pattern: JitterPattern,
+        base_phase_count: u32,
+        resolution_scale: [f32; 2],
+    ) -> Self {
+        assert!(
+            resolution_scale.iter().all(|s| s.is_finite() && *s > 0.0),
+            "resolution scale must be positive and finite"
+        );
+        let area = (resolution_scale[0] * resolution_scale[1]).max(1.0 / base_phase_count as f32);
+        let phase_count = (base_phase_count as f32 / area).ceil() as u32;
+        Self::new(pattern, phase_count.max(1))
+    }
+
+    pub fn pattern(&self) -> JitterPattern {
+        self.pattern
+    }
+
+    pub fn phase_count(&self) -> u32 {
+        self.phase_count
+    }
+
+    /// Index of the sample returned by the next call to [`JitterSequence::advance`].
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+
+    /// Pixel offset in [-0.5; 0.5] of the sample at `index`, wrapped to the phase count.
+    pub fn sample(&self, index: u32) -> [f32; 2] {
+        let n = index % self.phase_count;
+        match self.pattern {
+            // Index 0 of the Halton sequence is (0, 0), skip it.
+            JitterPattern::Halton23 => [
+                radical_inverse(n + 1, 2) as f32 - 0.5,
+                radical_inverse(n + 1, 3) as f32 - 0.5,
+            ],
+            JitterPattern::R2 => {
+                const G: f64 = 1.324_717_957_244_746;
+                let n = n as f64;
+                [
+                    ((0.5 + n / G).fract() - 0.5) as f32,
+                    ((0.5 + n / (G * G)).fract() - 0.5) as f32,
+                ]
+            }
+        }
+    }
+
+    /// Returns the jitter for the current frame and moves to the next sample.
+    pub fn advance(&mut self, render_width: u32, render_height: u32) -> Jitter {
+        let jitter = Jitter::new(self.sample(self.index), render_width, render_height);
+        self.index = (self.index + 1) % self.phase_count;
+        jitter
+    }
+}
+
+fn radical_inverse(mut index: u32, base: u32) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f64;
+    while index > 0 {
+        result += (index % base) as f64 * fraction;
+        index /= base;
+        fraction /= base as f64;
+    }
+    result
+}
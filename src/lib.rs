@@ -1,16 +1,59 @@
 #![feature(allocator_api)]
 
+use std::collections::HashMap;
 use std::ffi::c_void;
 
+mod builder;
+#[cfg(feature = "capture")]
+pub mod capture;
+pub mod family;
 mod ffi;
+mod format;
+mod frame;
+mod graph;
+#[cfg(feature = "egui")]
+mod inspector;
+mod jitter;
+mod managed;
+mod math;
+mod motion;
+mod multiview;
+mod owned;
+pub mod packing;
+mod preset;
+mod requirements;
+mod sampler;
+mod settings;
+pub mod sh;
+pub mod shader;
+mod validate;
+#[cfg(feature = "hot-reload")]
+pub mod watcher;
 
+pub use builder::{Handle, InstanceBuilder};
 pub use ffi::{
-    AccumulationMode, CheckerboardMode, CommonSettings, Denoiser, DenoiserDesc, DescriptorType,
-    DispatchDesc, Format, HitDistanceParameters, HitDistanceReconstructionMode, Identifier,
-    ReblurAntilagSettings, ReblurSettings, ReferenceSettings, RelaxAntilagSettings,
-    RelaxDiffuseSettings, RelaxDiffuseSpecularSettings, RelaxSpecularSettings, ResourceDesc,
-    ResourceType, SPIRVBindingOffsets, Sampler, SigmaSettings, TextureDesc,
+    AccumulationMode, CheckerboardMode, CommonSettings, Denoiser, DenoiserDesc, DescriptorPoolDesc,
+    DescriptorType, DispatchDesc, Format, HitDistanceParameters, HitDistanceReconstructionMode,
+    Identifier, InstanceDesc, LibraryDesc, NormalEncoding, PipelineDesc, ReblurAntilagSettings,
+    ReblurSettings, ReferenceSettings, RelaxAntilagSettings, RelaxDiffuseSettings,
+    RelaxDiffuseSpecularSettings, RelaxSpecularSettings, ResourceDesc, ResourceRangeDesc,
+    ResourceType, RoughnessEncoding, SPIRVBindingOffsets, Sampler, SigmaSettings, TextureDesc,
+    REBLUR_MAX_HISTORY_FRAME_NUM, RELAX_MAX_HISTORY_FRAME_NUM,
 };
+pub use format::{FormatError, NumericType};
+pub use frame::{Camera, FrameState};
+pub use graph::{DispatchGraph, GraphAccess, GraphDispatch, GraphResource};
+pub use jitter::{Jitter, JitterPattern, JitterSequence};
+pub use managed::ManagedInstance;
+pub use math::{ColumnMajor, IntoMatrix4, IntoVector2, IntoVector3, RowMajor};
+pub use motion::MotionVectorConvention;
+pub use multiview::{MultiViewDenoiser, ViewDispatch};
+pub use owned::{OwnedDispatchDesc, OwnedInstanceDesc, OwnedPipelineDesc};
+pub use preset::Preset;
+pub use requirements::SettingsKind;
+pub use sampler::{SamplerAddressMode, SamplerDesc, SamplerFilter};
+pub use settings::{AnyDenoiserSettings, SettingsChanges};
+pub use validate::{SettingsError, Validate, Violation};
 
 mod allocator {
     use std::alloc::{Allocator, Layout};
@@ -55,7 +98,7 @@ mod allocator {
     }
 }
 
-pub trait DenoiserSettings {}
+pub trait DenoiserSettings: Clone + Into<AnyDenoiserSettings> {}
 impl DenoiserSettings for ffi::ReblurSettings {}
 impl DenoiserSettings for ffi::RelaxDiffuseSettings {}
 impl DenoiserSettings for ffi::RelaxDiffuseSpecularSettings {}
@@ -63,7 +106,16 @@ impl DenoiserSettings for ffi::RelaxSpecularSettings {}
 impl DenoiserSettings for ffi::ReferenceSettings {}
 impl DenoiserSettings for ffi::SigmaSettings {}
 
-pub struct Instance(*mut c_void);
+pub struct Instance {
+    ptr: *mut c_void,
+    denoisers: Vec<ffi::DenoiserDesc>,
+    // The `InstanceBuilder` whose handles are valid for this instance, 0 if there is none
+    builder: u64,
+    // Last settings NRD accepted, used to skip redundant uploads
+    common_settings: Option<ffi::CommonSettings>,
+    denoiser_settings: HashMap<ffi::Identifier, AnyDenoiserSettings>,
+    changes: SettingsChanges,
+}
 unsafe impl Send for Instance {}
 unsafe impl Sync for Instance {}
 
@@ -85,43 +137,114 @@ impl Instance {
         let mut ptr: *mut c_void = std::ptr::null_mut();
         let result = unsafe { ffi::CreateInstance(&desc, &mut ptr) };
         match result {
-            ffi::Result::Success => Ok(Self(ptr)),
+            ffi::Result::Success => Ok(Self {
+                ptr,
+                denoisers: denoisers.to_vec(),
+                builder: 0,
+                common_settings: None,
+                denoiser_settings: HashMap::new(),
+                changes: SettingsChanges::default(),
+            }),
             _ => Err(result),
         }
     }
 
     pub fn desc(&self) -> &ffi::InstanceDesc {
         unsafe {
-            let ptr = ffi::GetInstanceDesc(self.0);
+            let ptr = ffi::GetInstanceDesc(self.ptr);
             &*ptr
         }
     }
 
+    /// The denoiser created with `identifier`.
+    pub fn denoiser(&self, identifier: ffi::Identifier) -> Option<ffi::Denoiser> {
+        self.denoisers
+            .iter()
+            .find(|desc| desc.identifier == identifier)
+            .map(|desc| desc.denoiser)
+    }
+
+    /// Forwards the settings unless they are identical to the last ones NRD accepted.
     pub fn set_common_settings(
         &mut self,
         settings: &ffi::CommonSettings,
     ) -> Result<(), ffi::Result> {
-        unsafe {
-            let result = ffi::SetCommonSettings(self.0, settings);
-            match result {
-                ffi::Result::Success => Ok(()),
-                _ => Err(result),
-            }
+        if self.common_settings.as_ref() == Some(settings) {
+            return Ok(());
         }
+        unsafe { ffi::SetCommonSettings(self.ptr, settings).ok(())? };
+        self.changes.common |= !self
+            .common_settings
+            .as_ref()
+            .is_some_and(|last| settings::tuned_common_settings_eq(last, settings));
+        self.common_settings = Some(settings.clone());
+        Ok(())
     }
-    pub fn set_denoiser_settings<T>(
+
+    /// Forwards the settings unless they are identical to the last ones NRD accepted for this
+    /// denoiser.
+    ///
+    /// Returns `InvalidArgument` without calling NRD if `identifier` is unknown or `T` isn't the
+    /// settings struct of its denoiser, as NRD would read the settings as the wrong type.
+    pub fn set_denoiser_settings<T: DenoiserSettings>(
         &mut self,
         identifier: ffi::Identifier,
-        reblur_settings: &T,
+        settings: &T,
     ) -> Result<(), ffi::Result> {
+        let any: AnyDenoiserSettings = settings.clone().into();
+        if self
+            .denoiser(identifier)
+            .and_then(ffi::Denoiser::settings_kind)
+            != Some(any.kind())
+        {
+            return Err(ffi::Result::InvalidArgument);
+        }
+        if self.denoiser_settings.get(&identifier) == Some(&any) {
+            return Ok(());
+        }
         unsafe {
-            ffi::SetDenoiserSettings(
-                self.0,
-                identifier,
-                reblur_settings as *const _ as *const c_void,
-            )
-            .ok(())
+            ffi::SetDenoiserSettings(self.ptr, identifier, settings as *const _ as *const c_void)
+                .ok(())?
+        };
+        self.denoiser_settings.insert(identifier, any);
+        if !self.changes.denoisers.contains(&identifier) {
+            self.changes.denoisers.push(identifier);
         }
+        Ok(())
+    }
+
+    /// The last `CommonSettings` NRD accepted.
+    pub fn common_settings(&self) -> Option<&ffi::CommonSettings> {
+        self.common_settings.as_ref()
+    }
+
+    /// The last settings NRD accepted for a denoiser.
+    pub fn denoiser_settings(&self, identifier: ffi::Identifier) -> Option<&AnyDenoiserSettings> {
+        self.denoiser_settings.get(&identifier)
+    }
+
+    /// Returns what changed since the previous call, call it once per frame.
+    pub fn take_changes(&mut self) -> SettingsChanges {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// Like [`Instance::set_common_settings`], but refuses settings that fail [`Validate`].
+    pub fn set_common_settings_checked(
+        &mut self,
+        settings: &ffi::CommonSettings,
+    ) -> Result<(), SettingsError> {
+        settings.validate().map_err(SettingsError::Invalid)?;
+        Ok(self.set_common_settings(settings)?)
+    }
+
+    /// Like [`Instance::set_denoiser_settings`], but refuses settings that fail [`Validate`].
+    pub fn set_denoiser_settings_checked<T: DenoiserSettings + Validate>(
+        &mut self,
+        identifier: ffi::Identifier,
+        settings: &T,
+    ) -> Result<(), SettingsError> {
+        settings.validate().map_err(SettingsError::Invalid)?;
+        Ok(self.set_denoiser_settings(identifier, settings)?)
     }
 
     pub fn get_compute_dispatches(
@@ -132,7 +255,7 @@ impl Instance {
             let mut dispatches: *const ffi::DispatchDesc = std::ptr::null();
             let mut dispatches_count: u32 = 0;
             let result = ffi::GetComputeDispatches(
-                self.0,
+                self.ptr,
                 identifiers.as_ptr(),
                 identifiers.len() as u32,
                 &mut dispatches,
@@ -152,7 +275,7 @@ impl Instance {
 impl Drop for Instance {
     fn drop(&mut self) {
         unsafe {
-            ffi::DestroyInstance(self.0);
+            ffi::DestroyInstance(self.ptr);
         }
     }
 }
@@ -0,0 +1,134 @@
+use crate::family::DenoiserFamily;
+use crate::ffi::{self, AccumulationMode, CommonSettings, DenoiserDesc, DispatchDesc, Identifier};
+use crate::{DenoiserSettings, Handle, Instance, InstanceBuilder};
+
+/// An [`Instance`] that is recreated when the render resolution changes.
+///
+/// NRD fixes the resolution of every denoiser at creation. For small per-frame changes prefer
+/// `CommonSettings::resolution_scale`, which needs no recreation; this wrapper is for window
+/// resizes and changes of the maximum render resolution.
+pub struct ManagedInstance {
+    denoisers: Vec<DenoiserDesc>,
+    instance: Instance,
+    // The next `CommonSettings` must clear the new instance's history
+    restart_pending: bool,
+}
+
+impl ManagedInstance {
+    pub fn new(denoisers: &[DenoiserDesc]) -> Result<Self, ffi::Result> {
+        Ok(Self {
+            denoisers: denoisers.to_vec(),
+            instance: Instance::new(denoisers)?,
+            restart_pending: false,
+        })
+    }
+
+    /// The handles returned by `builder` stay valid across resizes.
+    pub fn from_builder(builder: &InstanceBuilder) -> Result<Self, ffi::Result> {
+        Ok(Self {
+            denoisers: builder.denoisers().to_vec(),
+            instance: builder.build()?,
+            restart_pending: false,
+        })
+    }
+
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    pub fn denoisers(&self) -> &[DenoiserDesc] {
+        &self.denoisers
+    }
+
+    pub fn desc(&self) -> &ffi::InstanceDesc {
+        self.instance.desc()
+    }
+
+    /// Changes the render resolution of every denoiser. All denoisers end up with the same
+    /// resolution, even if they were created with different ones.
+    ///
+    /// Returns `true` if the instance was recreated, in which case the permanent and transient
+    /// pools must be reallocated from [`ManagedInstance::desc`] before the next dispatch. The
+    /// remembered denoiser settings are re-applied, the common settings are not: the next
+    /// [`ManagedInstance::set_common_settings`] clears history and uploads them. On error the
+    /// previous instance is kept.
+    #[must_use = "pool textures must be reallocated when the instance was recreated"]
+    pub fn resize(&mut self, render_width: u16, render_height: u16) -> Result<bool, ffi::Result> {
+        if self
+            .denoisers
+            .iter()
+            .all(|d| d.render_width == render_width && d.render_height == render_height)
+        {
+            return Ok(false);
+        }
+        let denoisers: Vec<_> = self
+            .denoisers
+            .iter()
+            .map(|d| DenoiserDesc {
+                render_width,
+                render_height,
+                ..d.clone()
+            })
+            .collect();
+        let mut instance = Instance::new(&denoisers)?;
+        instance.builder = self.instance.builder;
+
+        for desc in &denoisers {
+            if let Some(settings) = self.instance.denoiser_settings(desc.identifier) {
+                settings.apply(&mut instance, desc.identifier)?;
+            }
+        }
+        // Re-applying isn't a change from the caller's point of view
+        instance.take_changes();
+
+        self.denoisers = denoisers;
+        self.instance = instance;
+        self.restart_pending = true;
+        Ok(true)
+    }
+
+    /// Forwards to [`Instance::set_common_settings`], forcing
+    /// [`AccumulationMode::ClearAndRestart`] on the first call after a recreation.
+    pub fn set_common_settings(&mut self, settings: &CommonSettings) -> Result<(), ffi::Result> {
+        if self.restart_pending {
+            self.instance.set_common_settings(&CommonSettings {
+                accumulation_mode: AccumulationMode::ClearAndRestart,
+                ..settings.clone()
+            })?;
+            self.restart_pending = false;
+            Ok(())
+        } else {
+            self.instance.set_common_settings(settings)
+        }
+    }
+
+    pub fn set_denoiser_settings<T: DenoiserSettings>(
+        &mut self,
+        identifier: Identifier,
+        settings: &T,
+    ) -> Result<(), ffi::Result> {
+        self.instance.set_denoiser_settings(identifier, settings)
+    }
+
+    pub fn set_settings<F: DenoiserFamily>(
+        &mut self,
+        handle: Handle<F>,
+        settings: &F::Settings,
+    ) -> Result<(), ffi::Result>
+    where
+        F::Settings: DenoiserSettings,
+    {
+        self.instance.set_settings(handle, settings)
+    }
+
+    pub fn get_compute_dispatches(
+        &mut self,
+        identifiers: &[Identifier],
+    ) -> Result<&[DispatchDesc], ffi::Result> {
+        self.instance.get_compute_dispatches(identifiers)
+    }
+
+    pub fn take_changes(&mut self) -> crate::SettingsChanges {
+        self.instance.take_changes()
+    }
+}
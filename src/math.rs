@@ -0,0 +1,166 @@
+use crate::ffi::CommonSettings;
+
+/// A 4x4 matrix that can be converted into the layout NRD expects:
+/// column vectors, stored column-major.
+pub trait IntoMatrix4 {
+    fn into_column_major(self) -> [f32; 16];
+}
+
+/// A 2 component vector, such as `camera_jitter` or `resolution_scale`.
+pub trait IntoVector2 {
+    fn into_array(self) -> [f32; 2];
+}
+
+/// A 3 component vector, such as `motion_vector_scale`.
+pub trait IntoVector3 {
+    fn into_array(self) -> [f32; 3];
+}
+
+/// Matrix elements stored column by column, i.e. `m[column * 4 + row]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnMajor(pub [f32; 16]);
+
+/// Matrix elements stored row by row, i.e. `m[row * 4 + column]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowMajor(pub [f32; 16]);
+
+impl IntoMatrix4 for ColumnMajor {
+    fn into_column_major(self) -> [f32; 16] {
+        self.0
+    }
+}
+
+impl IntoMatrix4 for RowMajor {
+    fn into_column_major(self) -> [f32; 16] {
+        let m = self.0;
+        std::array::from_fn(|i| m[(i % 4) * 4 + i / 4])
+    }
+}
+
+impl IntoVector2 for [f32; 2] {
+    fn into_array(self) -> [f32; 2] {
+        self
+    }
+}
+
+impl IntoVector3 for [f32; 3] {
+    fn into_array(self) -> [f32; 3] {
+        self
+    }
+}
+
+#[cfg(feature = "glam")]
+impl IntoMatrix4 for glam::Mat4 {
+    fn into_column_major(self) -> [f32; 16] {
+        self.to_cols_array()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl IntoVector2 for glam::Vec2 {
+    fn into_array(self) -> [f32; 2] {
+        self.to_array()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl IntoVector3 for glam::Vec3 {
+    fn into_array(self) -> [f32; 3] {
+        self.to_array()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl IntoMatrix4 for nalgebra::Matrix4<f32> {
+    fn into_column_major(self) -> [f32; 16] {
+        // nalgebra storage is column-major.
+        self.as_slice().try_into().unwrap()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl IntoVector2 for nalgebra::Vector2<f32> {
+    fn into_array(self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl IntoVector3 for nalgebra::Vector3<f32> {
+    fn into_array(self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoMatrix4 for mint::ColumnMatrix4<f32> {
+    fn into_column_major(self) -> [f32; 16] {
+        let columns: [[f32; 4]; 4] = self.into();
+        std::array::from_fn(|i| columns[i / 4][i % 4])
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoMatrix4 for mint::RowMatrix4<f32> {
+    fn into_column_major(self) -> [f32; 16] {
+        let rows: [[f32; 4]; 4] = self.into();
+        std::array::from_fn(|i| rows[i % 4][i / 4])
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoVector2 for mint::Vector2<f32> {
+    fn into_array(self) -> [f32; 2] {
+        self.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoVector3 for mint::Vector3<f32> {
+    fn into_array(self) -> [f32; 3] {
+        self.into()
+    }
+}
+
+impl CommonSettings {
+    pub fn with_view_to_clip(mut self, matrix: impl IntoMatrix4) -> Self {
+        self.view_to_clip_matrix = matrix.into_column_major();
+        self
+    }
+    pub fn with_view_to_clip_prev(mut self, matrix: impl IntoMatrix4) -> Self {
+        self.view_to_clip_matrix_prev = matrix.into_column_major();
+        self
+    }
+    pub fn with_world_to_view(mut self, matrix: impl IntoMatrix4) -> Self {
+        self.world_to_view_matrix = matrix.into_column_major();
+        self
+    }
+    pub fn with_world_to_view_prev(mut self, matrix: impl IntoMatrix4) -> Self {
+        self.world_to_view_matrix_prev = matrix.into_column_major();
+        self
+    }
+    pub fn with_world_prev_to_world(mut self, matrix: impl IntoMatrix4) -> Self {
+        self.world_prev_to_world_matrix = matrix.into_column_major();
+        self
+    }
+    pub fn with_motion_vector_scale(mut self, scale: impl IntoVector3) -> Self {
+        self.motion_vector_scale = scale.into_array();
+        self
+    }
+    pub fn with_camera_jitter(mut self, jitter: impl IntoVector2) -> Self {
+        self.camera_jitter = jitter.into_array();
+        self
+    }
+    pub fn with_camera_jitter_prev(mut self, jitter: impl IntoVector2) -> Self {
+        self.camera_jitter_prev = jitter.into_array();
+        self
+    }
+    pub fn with_resolution_scale(mut self, scale: impl IntoVector2) -> Self {
+        self.resolution_scale = scale.into_array();
+        self
+    }
+    pub fn with_resolution_scale_prev(mut self, scale: impl IntoVector2) -> Self {
+        self.resolution_scale_prev = scale.into_array();
+        self
+    }
+}
@@ -0,0 +1,46 @@
+use crate::ffi::CommonSettings;
+
+/// Space in which the renderer writes IN_MV. In every convention the motion must be
+/// "previous - current" and non jittered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionVectorConvention {
+    // 2D screen-space motion in UV units, [0; 1] across the render target
+    Uv,
+
+    // 2D screen-space motion in pixels of the render resolution
+    Pixels,
+
+    // 2D screen-space motion in NDC units, [-1; 1] across the render target, +Y down (Vulkan)
+    Ndc,
+
+    // 2D screen-space motion in NDC units, [-1; 1] across the render target, +Y up (D3D, OpenGL)
+    NdcYUp,
+
+    // 3D world-space motion
+    World,
+}
+
+impl MotionVectorConvention {
+    /// Value for `CommonSettings::motion_vector_scale`, converting IN_MV into UV units.
+    /// 2D conventions leave the optional view Z delta in `.z` unused.
+    pub fn motion_vector_scale(self, render_width: u32, render_height: u32) -> [f32; 3] {
+        match self {
+            Self::Uv => [1.0, 1.0, 0.0],
+            Self::Pixels => [1.0 / render_width as f32, 1.0 / render_height as f32, 0.0],
+            Self::Ndc => [0.5, 0.5, 0.0],
+            Self::NdcYUp => [0.5, -0.5, 0.0],
+            Self::World => [1.0, 1.0, 1.0],
+        }
+    }
+
+    /// Value for `CommonSettings::is_motion_vector_in_world_space`.
+    pub fn is_world_space(self) -> bool {
+        matches!(self, Self::World)
+    }
+
+    /// Writes the motion vector fields of `settings`.
+    pub fn apply(self, settings: &mut CommonSettings, render_width: u32, render_height: u32) {
+        settings.motion_vector_scale = self.motion_vector_scale(render_width, render_height);
+        settings.is_motion_vector_in_world_space = self.is_world_space();
+    }
+}
@@ -0,0 +1,122 @@
+use std::time::Instant;
+
+use crate::ffi::{self, CommonSettings, DenoiserDesc, DispatchDesc, Identifier, PipelineDesc};
+use crate::{Camera, DenoiserSettings, FrameState, Instance};
+
+/// Denoises several views, e.g. the eyes of a VR headset or split-screen players.
+///
+/// NRD keeps a single `CommonSettings` history per instance, so every view gets its own
+/// [`Instance`] created from the same denoisers. The pipelines are identical across views and
+/// only need to be created once from [`MultiViewDenoiser::pipelines`], while the permanent and
+/// transient pools must be allocated per view.
+pub struct MultiViewDenoiser {
+    views: Vec<View>,
+}
+
+struct View {
+    instance: Instance,
+    frame: FrameState,
+}
+
+/// A dispatch of one view, see [`MultiViewDenoiser::get_compute_dispatches`].
+#[derive(Debug, Clone, Copy)]
+pub struct ViewDispatch<'a> {
+    pub view: usize,
+    pub dispatch: &'a DispatchDesc,
+}
+
+impl MultiViewDenoiser {
+    /// `settings` provides the initial fields of every view's [`FrameState`].
+    pub fn new(
+        denoisers: &[DenoiserDesc],
+        view_count: usize,
+        settings: CommonSettings,
+    ) -> Result<Self, ffi::Result> {
+        assert!(view_count > 0, "at least one view is required");
+        let views = (0..view_count)
+            .map(|_| {
+                Ok(View {
+                    instance: Instance::new(denoisers)?,
+                    frame: FrameState::new(settings.clone()),
+                })
+            })
+            .collect::<Result<_, ffi::Result>>()?;
+        Ok(Self { views })
+    }
+
+    pub fn view_count(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Pipelines shared by all views. `pipeline_index` of every [`ViewDispatch`] indexes into it.
+    pub fn pipelines(&self) -> &[PipelineDesc] {
+        self.views[0].instance.desc().pipelines()
+    }
+
+    pub fn instance(&self, view: usize) -> &Instance {
+        &self.views[view].instance
+    }
+
+    pub fn instance_mut(&mut self, view: usize) -> &mut Instance {
+        &mut self.views[view].instance
+    }
+
+    pub fn frame_state(&self, view: usize) -> &FrameState {
+        &self.views[view].frame
+    }
+
+    /// Per-view history control, e.g. [`FrameState::restart`] after a camera cut in one view.
+    pub fn frame_state_mut(&mut self, view: usize) -> &mut FrameState {
+        &mut self.views[view].frame
+    }
+
+    /// Sets the same denoiser settings on every view.
+    pub fn set_denoiser_settings<T: DenoiserSettings>(
+        &mut self,
+        identifier: Identifier,
+        settings: &T,
+    ) -> Result<(), ffi::Result> {
+        for view in &mut self.views {
+            view.instance.set_denoiser_settings(identifier, settings)?;
+        }
+        Ok(())
+    }
+
+    /// Advances every view by one frame. `cameras` holds one camera per view.
+    pub fn advance(&mut self, cameras: &[Camera], timestamp: Instant) -> Result<(), ffi::Result> {
+        assert_eq!(
+            cameras.len(),
+            self.views.len(),
+            "expected one camera per view"
+        );
+        for (view, camera) in self.views.iter_mut().zip(cameras) {
+            let settings = view.frame.advance(camera, timestamp);
+            view.instance.set_common_settings(settings)?;
+        }
+        Ok(())
+    }
+
+    /// Dispatches of all views, interleaved so that the same pass of every view is recorded
+    /// back to back and pipeline switches are minimized.
+    pub fn get_compute_dispatches(
+        &mut self,
+        identifiers: &[Identifier],
+    ) -> Result<Vec<ViewDispatch<'_>>, ffi::Result> {
+        let per_view = self
+            .views
+            .iter_mut()
+            .map(|view| view.instance.get_compute_dispatches(identifiers))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let len = per_view.iter().map(|d| d.len()).max().unwrap_or(0);
+        let mut dispatches = Vec::with_capacity(per_view.iter().map(|d| d.len()).sum());
+        for i in 0..len {
+            for (view, view_dispatches) in per_view.iter().enumerate() {
+                if let Some(dispatch) = view_dispatches.get(i) {
+                    dispatches.push(ViewDispatch { view, dispatch });
+                }
+            }
+        }
+        Ok(dispatches)
+    }
+}
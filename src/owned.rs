@@ -0,0 +1,97 @@
+//! Owned copies of the descriptions returned by NRD.
+//!
+//! [`InstanceDesc`] and [`DispatchDesc`] borrow memory owned by the library and are only valid
+//! until the instance is destroyed or the next `GetComputeDispatches` call. The types here copy
+//! everything out so the descriptions can be stored, compared or serialized.
+
+use crate::ffi::{
+    DescriptorPoolDesc, DispatchDesc, InstanceDesc, PipelineDesc, ResourceDesc, ResourceRangeDesc,
+    Sampler, TextureDesc,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedPipelineDesc {
+    pub compute_shader_dxbc: Vec<u8>,
+    pub compute_shader_dxil: Vec<u8>,
+    pub compute_shader_spirv: Vec<u8>,
+    pub shader_file_name: String,
+    pub shader_entry_point_name: String,
+    pub resource_ranges: Vec<ResourceRangeDesc>,
+    pub has_constant_data: bool,
+}
+
+impl From<&PipelineDesc> for OwnedPipelineDesc {
+    fn from(desc: &PipelineDesc) -> Self {
+        Self {
+            compute_shader_dxbc: desc.compute_shader_dxbc.to_vec(),
+            compute_shader_dxil: desc.compute_shader_dxil.to_vec(),
+            compute_shader_spirv: desc.compute_shader_spirv.to_vec(),
+            shader_file_name: desc.shader_file_name().to_string_lossy().into_owned(),
+            shader_entry_point_name: desc
+                .shader_entry_point_name()
+                .to_string_lossy()
+                .into_owned(),
+            resource_ranges: desc.resource_ranges().to_vec(),
+            has_constant_data: desc.has_constant_data,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedInstanceDesc {
+    pub constant_buffer_max_data_size: u32,
+    pub constant_buffer_space_index: u32,
+    pub constant_buffer_register_index: u32,
+    pub samplers: Vec<Sampler>,
+    pub samplers_space_index: u32,
+    pub samplers_base_register_index: u32,
+    pub pipelines: Vec<OwnedPipelineDesc>,
+    pub resources_space_index: u32,
+    pub permanent_pool: Vec<TextureDesc>,
+    pub transient_pool: Vec<TextureDesc>,
+    pub descriptor_pool_desc: DescriptorPoolDesc,
+}
+
+impl From<&InstanceDesc> for OwnedInstanceDesc {
+    fn from(desc: &InstanceDesc) -> Self {
+        Self {
+            constant_buffer_max_data_size: desc.constant_buffer_max_data_size,
+            constant_buffer_space_index: desc.constant_buffer_space_index,
+            constant_buffer_register_index: desc.constant_buffer_register_index,
+            samplers: desc.samplers().to_vec(),
+            samplers_space_index: desc.samplers_space_index,
+            samplers_base_register_index: desc.samplers_base_register_index,
+            pipelines: desc.pipelines().iter().map(Into::into).collect(),
+            resources_space_index: desc.resources_space_index,
+            permanent_pool: desc.permanent_pool().to_vec(),
+            transient_pool: desc.transient_pool().to_vec(),
+            descriptor_pool_desc: desc.descriptor_pool_desc.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedDispatchDesc {
+    pub name: String,
+    pub resources: Vec<ResourceDesc>,
+    pub constant_buffer: Vec<u8>,
+    pub pipeline_index: u16,
+    pub grid_width: u16,
+    pub grid_height: u16,
+}
+
+impl From<&DispatchDesc> for OwnedDispatchDesc {
+    fn from(desc: &DispatchDesc) -> Self {
+        Self {
+            name: desc.name().to_string_lossy().into_owned(),
+            resources: desc.resources().to_vec(),
+            constant_buffer: desc.constant_buffer().to_vec(),
+            pipeline_index: desc.pipeline_index,
+            grid_width: desc.grid_width,
+            grid_height: desc.grid_height,
+        }
+    }
+}
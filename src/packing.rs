@@ -0,0 +1,453 @@
+//! CPU versions of the packing functions from `NRD.hlsli`, used to produce denoiser inputs
+//! exactly as the shaders would and to decode denoiser outputs read back from the GPU.
+//!
+//! Unlike the shader code, the normal and roughness encodings are passed in explicitly. Use the
+//! values reported by [`LibraryDesc`](crate::LibraryDesc) to match the linked library.
+
+use crate::ffi::{
+    Denoiser, HitDistanceParameters, NormalEncoding, ResourceType, RoughnessEncoding,
+};
+use crate::sh::Sg;
+use crate::SettingsKind;
+
+/// Smallest valid hit distance, "0" marks samples without data.
+pub const FP16_MIN: f32 = 1e-7;
+
+/// Largest value representable in the FP16 textures used by NRD.
+pub const FP16_MAX: f32 = 65504.0;
+
+/// Scale applied to view Z stored in FP16 textures.
+pub const FP16_VIEWZ_SCALE: f32 = 0.125;
+
+// Not 0, because it means "NoL < 0, stop processing"
+const SIGMA_MIN_DISTANCE: f32 = 0.0001;
+
+pub(crate) const EPS: f32 = 1e-6;
+
+/// `NRD_FrontEnd_PackNormalAndRoughness`
+///
+/// `material_id` in [0; 3] is only stored with [`NormalEncoding::R10G10B10A2Unorm`].
+pub fn pack_normal_and_roughness(
+    normal: [f32; 3],
+    linear_roughness: f32,
+    material_id: f32,
+    normal_encoding: NormalEncoding,
+    roughness_encoding: RoughnessEncoding,
+) -> [f32; 4] {
+    let roughness = match roughness_encoding {
+        RoughnessEncoding::SqrtLinear => saturate(linear_roughness).sqrt(),
+        RoughnessEncoding::SqLinear => linear_roughness * linear_roughness,
+        RoughnessEncoding::LINEAR => linear_roughness,
+    };
+
+    match normal_encoding {
+        NormalEncoding::R10G10B10A2Unorm => {
+            let [x, y] = encode_unit_vector(normal, false);
+            [x, y, roughness, saturate(material_id / 3.0)]
+        }
+        _ => {
+            // Best fit
+            let [x, y, z] = normal;
+            let n = scale(normal, 1.0 / x.abs().max(y.abs().max(z.abs())));
+            let [x, y, z] = match normal_encoding {
+                NormalEncoding::Rgba8Unorm | NormalEncoding::Rgba16Unorm => {
+                    n.map(|c| c * 0.5 + 0.5)
+                }
+                _ => n,
+            };
+            [x, y, z, roughness]
+        }
+    }
+}
+
+/// `NRD_FrontEnd_UnpackNormalAndRoughness`
+///
+/// Returns the normalized normal, the linear roughness and the material ID in [0; 3].
+pub fn unpack_normal_and_roughness(
+    packed: [f32; 4],
+    normal_encoding: NormalEncoding,
+    roughness_encoding: RoughnessEncoding,
+) -> ([f32; 3], f32, f32) {
+    let (normal, roughness, material_id) = match normal_encoding {
+        NormalEncoding::R10G10B10A2Unorm => (
+            decode_unit_vector([packed[0], packed[1]], false),
+            packed[2],
+            packed[3] * 3.0,
+        ),
+        _ => {
+            let n = [packed[0], packed[1], packed[2]];
+            let n = match normal_encoding {
+                NormalEncoding::Rgba8Unorm | NormalEncoding::Rgba16Unorm => {
+                    n.map(|c| c * 2.0 - 1.0)
+                }
+                _ => n,
+            };
+            (n, packed[3], 0.0)
+        }
+    };
+
+    let roughness = match roughness_encoding {
+        RoughnessEncoding::SqrtLinear => roughness * roughness,
+        RoughnessEncoding::SqLinear => saturate(roughness).sqrt(),
+        RoughnessEncoding::LINEAR => roughness,
+    };
+
+    (safe_normalize(normal), roughness, material_id)
+}
+
+/// `REBLUR_FrontEnd_PackRadianceAndNormHitDist`
+///
+/// `norm_hit_dist` is the output of [`HitDistanceParameters::normalize`].
+pub fn reblur_pack_radiance_and_norm_hit_dist(
+    radiance: [f32; 3],
+    norm_hit_dist: f32,
+    sanitize: bool,
+) -> [f32; 4] {
+    let (radiance, mut norm_hit_dist) = if sanitize {
+        (
+            sanitize_radiance(radiance),
+            sanitize_scalar(norm_hit_dist, 1.0),
+        )
+    } else {
+        (radiance, norm_hit_dist)
+    };
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if norm_hit_dist != 0.0 {
+        norm_hit_dist = norm_hit_dist.max(FP16_MIN);
+    }
+
+    let [y, co, cg] = linear_to_ycocg(radiance);
+    [y, co, cg, norm_hit_dist]
+}
+
+/// `REBLUR_FrontEnd_PackDirectionalOcclusion`, returns the IN_DIFF_DIRECTION_HITDIST texel.
+///
+/// `direction` is the normalized direction of the sample, `norm_hit_dist` is the output of
+/// [`HitDistanceParameters::normalize`].
+pub fn reblur_pack_directional_occlusion(
+    direction: [f32; 3],
+    norm_hit_dist: f32,
+    sanitize: bool,
+) -> [f32; 4] {
+    let (direction, mut norm_hit_dist) = if sanitize {
+        let direction = if direction.iter().all(|c| c.is_finite()) {
+            direction
+        } else {
+            [0.0; 3]
+        };
+        (direction, sanitize_scalar(norm_hit_dist, 1.0))
+    } else {
+        (direction, norm_hit_dist)
+    };
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if norm_hit_dist != 0.0 {
+        norm_hit_dist = norm_hit_dist.max(FP16_MIN);
+    }
+
+    let [x, y, z] = scale(direction, norm_hit_dist);
+    [x, y, z, norm_hit_dist]
+}
+
+impl HitDistanceParameters {
+    /// Default parameters for a scene where one meter spans `units_per_meter` units. Only the
+    /// constant term is measured in units, the others are relative.
+    pub fn from_units_per_meter(units_per_meter: f32) -> Self {
+        let default = Self::default();
+        Self {
+            a: default.a * units_per_meter,
+            ..default
+        }
+    }
+
+    /// `_REBLUR_GetHitDistanceNormalization`
+    pub fn normalization(&self, view_z: f32, roughness: f32) -> f32 {
+        let t = saturate((self.d * roughness * roughness).exp2());
+        (self.a + view_z.abs() * self.b) * (1.0 + (self.c - 1.0) * t)
+    }
+
+    /// `REBLUR_FrontEnd_GetNormHitDist`
+    pub fn normalize(&self, view_z: f32, roughness: f32, hit_dist: f32) -> f32 {
+        saturate(hit_dist / self.normalization(view_z, roughness))
+    }
+
+    /// `REBLUR_GetHitDist`, the inverse of [`HitDistanceParameters::normalize`] for hit
+    /// distances within the normalization range.
+    pub fn denormalize(&self, view_z: f32, roughness: f32, norm_hit_dist: f32) -> f32 {
+        norm_hit_dist * self.normalization(view_z, roughness)
+    }
+}
+
+/// `RELAX_FrontEnd_PackRadianceAndHitDist`
+pub fn relax_pack_radiance_and_hit_dist(
+    radiance: [f32; 3],
+    hit_dist: f32,
+    sanitize: bool,
+) -> [f32; 4] {
+    let (radiance, mut hit_dist) = if sanitize {
+        (
+            sanitize_radiance(radiance),
+            sanitize_scalar(hit_dist, FP16_MAX),
+        )
+    } else {
+        (radiance, hit_dist)
+    };
+
+    // "0" is reserved to mark "no data" samples, skipped due to probabilistic sampling
+    if hit_dist != 0.0 {
+        hit_dist = hit_dist.max(FP16_MIN);
+    }
+
+    let [r, g, b] = radiance;
+    [r, g, b, hit_dist]
+}
+
+/// `REBLUR_BackEnd_UnpackRadianceAndNormHitDist`
+pub fn reblur_unpack_radiance_and_norm_hit_dist(packed: [f32; 4]) -> [f32; 4] {
+    let [r, g, b] = ycocg_to_linear([packed[0], packed[1], packed[2]]);
+    [r, g, b, packed[3]]
+}
+
+/// `REBLUR_BackEnd_UnpackDirectionalOcclusion`, the normalized hit distance is returned in both
+/// `c0` and `hit_dist`.
+pub fn reblur_unpack_directional_occlusion(packed: [f32; 4]) -> Sg {
+    Sg {
+        c0: packed[3],
+        chroma: [0.0; 2],
+        hit_dist: packed[3],
+        c1: [packed[0], packed[1], packed[2]],
+    }
+}
+
+/// `RELAX_BackEnd_UnpackRadiance`
+pub fn relax_unpack_radiance(packed: [f32; 4]) -> [f32; 4] {
+    packed
+}
+
+/// Shape of a shadow casting light, used to derive the penumbra size for SIGMA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShadowLight {
+    /// Directional light, such as the sun, covering a cone with the given half angle (radians)
+    Directional { angular_radius: f32 },
+
+    /// Spherical point or spot light of "radius", "distance" away from the shaded point
+    Sphere { radius: f32, distance: f32 },
+}
+
+impl ShadowLight {
+    /// The `tanOfLightAngularRadius` argument of [`sigma_pack_shadow`].
+    pub fn tan_of_angular_radius(&self) -> f32 {
+        match *self {
+            ShadowLight::Directional { angular_radius } => angular_radius.tan(),
+            ShadowLight::Sphere { radius, distance } => {
+                // Inside the light the whole hemisphere is covered
+                let d2 = distance * distance - radius * radius;
+                if d2 > 0.0 {
+                    radius / d2.sqrt()
+                } else {
+                    FP16_MAX
+                }
+            }
+        }
+    }
+}
+
+/// `SIGMA_FrontEnd_PackShadow`, returns the IN_SHADOWDATA texel.
+///
+/// `distance_to_occluder` is [`FP16_MAX`] if the shadow ray didn't hit anything, and 0 if the
+/// surface faces away from the light.
+pub fn sigma_pack_shadow(
+    view_z: f32,
+    distance_to_occluder: f32,
+    tan_of_light_angular_radius: f32,
+) -> [f32; 2] {
+    let penumbra = if distance_to_occluder == FP16_MAX {
+        FP16_MAX
+    } else if distance_to_occluder != 0.0 {
+        (distance_to_occluder * tan_of_light_angular_radius).clamp(SIGMA_MIN_DISTANCE, 32768.0)
+    } else {
+        0.0
+    };
+    [penumbra, pack_view_z(view_z)]
+}
+
+/// `SIGMA_FrontEnd_PackShadow` with translucency, returns the IN_SHADOWDATA and
+/// IN_SHADOW_TRANSLUCENCY texels.
+pub fn sigma_pack_shadow_translucency(
+    view_z: f32,
+    distance_to_occluder: f32,
+    tan_of_light_angular_radius: f32,
+    translucency: [f32; 3],
+) -> ([f32; 2], [f32; 4]) {
+    let lit = if distance_to_occluder == FP16_MAX {
+        1.0
+    } else {
+        0.0
+    };
+    let [r, g, b] = translucency.map(saturate);
+    (
+        sigma_pack_shadow(view_z, distance_to_occluder, tan_of_light_angular_radius),
+        [lit, r, g, b],
+    )
+}
+
+/// Inverse of [`sigma_pack_shadow`], returns the view Z and the penumbra size in world units
+/// ([`FP16_MAX`] if lit, 0 if facing away from the light).
+pub fn sigma_unpack_shadow_data(packed: [f32; 2]) -> (f32, f32) {
+    (packed[1] / FP16_VIEWZ_SCALE, packed[0])
+}
+
+/// `SIGMA_BackEnd_UnpackShadow`
+pub fn sigma_unpack_shadow(packed: [f32; 4]) -> [f32; 4] {
+    packed.map(|c| c * c)
+}
+
+/// Decodes a texel of the `resource` output of `denoiser`.
+///
+/// Returns `None` if `resource` is not an output of `denoiser`, or if it can't be decoded one
+/// texture at a time. SH outputs are resolved with [`crate::sh`]. `OUT_DIFF_DIRECTION_HITDIST`
+/// decodes to the dominant direction and the normalized hit distance.
+pub fn unpack_output(
+    denoiser: Denoiser,
+    resource: ResourceType,
+    texel: [f32; 4],
+) -> Option<[f32; 4]> {
+    use Denoiser::*;
+    use ResourceType::*;
+
+    if !denoiser.outputs().contains(&resource) {
+        return None;
+    }
+
+    let kind = denoiser.settings_kind();
+    let reblur = kind == Some(SettingsKind::Reblur);
+    let relax = matches!(
+        kind,
+        Some(
+            SettingsKind::RelaxDiffuse
+                | SettingsKind::RelaxSpecular
+                | SettingsKind::RelaxDiffuseSpecular
+        )
+    );
+    let sigma = kind == Some(SettingsKind::Sigma);
+
+    match resource {
+        OUT_DIFF_RADIANCE_HITDIST | OUT_SPEC_RADIANCE_HITDIST if reblur => {
+            Some(reblur_unpack_radiance_and_norm_hit_dist(texel))
+        }
+        OUT_DIFF_RADIANCE_HITDIST | OUT_SPEC_RADIANCE_HITDIST if relax => {
+            Some(relax_unpack_radiance(texel))
+        }
+        OUT_DIFF_HITDIST | OUT_SPEC_HITDIST if reblur => Some(texel),
+        OUT_DIFF_DIRECTION_HITDIST => {
+            let sg = reblur_unpack_directional_occlusion(texel);
+            let [x, y, z] = sg.direction();
+            Some([x, y, z, sg.hit_dist])
+        }
+        OUT_SHADOW_TRANSLUCENCY if sigma => Some(sigma_unpack_shadow(texel)),
+        OUT_RADIANCE if matches!(denoiser, Reference) => Some(texel),
+        OUT_REFLECTION_MV if matches!(denoiser, SpecularReflectionMv) => Some(texel),
+        OUT_DELTA_MV if matches!(denoiser, SpecularDeltaMv) => Some(texel),
+        OUT_VALIDATION => Some(texel),
+        _ => None,
+    }
+}
+
+/// Decodes a whole output texture, see [`unpack_output`].
+pub fn unpack_output_image(
+    denoiser: Denoiser,
+    resource: ResourceType,
+    texels: &[[f32; 4]],
+) -> Option<Vec<[f32; 4]>> {
+    texels
+        .iter()
+        .map(|&texel| unpack_output(denoiser, resource, texel))
+        .collect()
+}
+
+// _NRD_PackViewZ
+fn pack_view_z(view_z: f32) -> f32 {
+    (view_z * FP16_VIEWZ_SCALE).clamp(-FP16_MAX, FP16_MAX)
+}
+
+// _NRD_EncodeUnitVector
+fn encode_unit_vector(v: [f32; 3], signed: bool) -> [f32; 2] {
+    let l1 = v[0].abs() + v[1].abs() + v[2].abs();
+    let (x, y) = (v[0] / l1, v[1] / l1);
+    let oct_wrap = [
+        (1.0 - y.abs()) * sign_not_zero(x),
+        (1.0 - x.abs()) * sign_not_zero(y),
+    ];
+    let p = if v[2] >= 0.0 { [x, y] } else { oct_wrap };
+    if signed {
+        p
+    } else {
+        p.map(|c| c * 0.5 + 0.5)
+    }
+}
+
+// _NRD_DecodeUnitVector
+fn decode_unit_vector(p: [f32; 2], signed: bool) -> [f32; 3] {
+    let p = if signed { p } else { p.map(|c| c * 2.0 - 1.0) };
+    let z = 1.0 - p[0].abs() - p[1].abs();
+    let t = saturate(-z);
+    [
+        p[0] - t * sign_not_zero(p[0]),
+        p[1] - t * sign_not_zero(p[1]),
+        z,
+    ]
+}
+
+// _NRD_LinearToYCoCg
+pub(crate) fn linear_to_ycocg([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let y = r * 0.25 + g * 0.5 + b * 0.25;
+    let co = r * 0.5 - b * 0.5;
+    let cg = -r * 0.25 + g * 0.5 - b * 0.25;
+    [y, co, cg]
+}
+
+// _NRD_YCoCgToLinear
+pub(crate) fn ycocg_to_linear([y, co, cg]: [f32; 3]) -> [f32; 3] {
+    let t = y - cg;
+    [t + co, y + cg, t - co].map(|c| c.max(0.0))
+}
+
+// _NRD_SafeNormalize
+pub(crate) fn safe_normalize(v: [f32; 3]) -> [f32; 3] {
+    let length_sq = v[0] * v[0] + v[1] * v[1] + v[2] * v[2];
+    scale(v, 1.0 / length_sq.max(EPS).sqrt())
+}
+
+fn sanitize_radiance(radiance: [f32; 3]) -> [f32; 3] {
+    if radiance.iter().any(|c| !c.is_finite()) {
+        [0.0; 3]
+    } else {
+        radiance.map(|c| c.clamp(0.0, FP16_MAX))
+    }
+}
+
+fn sanitize_scalar(value: f32, max: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(0.0, max)
+    } else {
+        0.0
+    }
+}
+
+// step( 0.0, x ) * 2.0 - 1.0
+fn sign_not_zero(x: f32) -> f32 {
+    if x >= 0.0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+fn saturate(x: f32) -> f32 {
+    x.clamp(0.0, 1.0)
+}
+
+fn scale(v: [f32; 3], s: f32) -> [f32; 3] {
+    v.map(|c| c * s)
+}
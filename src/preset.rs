@@ -0,0 +1,226 @@
+use crate::ffi::{
+    ReblurSettings, RelaxDiffuseSettings, RelaxDiffuseSpecularSettings, RelaxSpecularSettings,
+    SigmaSettings, REBLUR_MAX_HISTORY_FRAME_NUM, RELAX_MAX_HISTORY_FRAME_NUM,
+};
+
+/// Curated starting points for denoiser settings.
+///
+/// Presets only produce a settings struct, so individual fields can still be overridden:
+/// ```
+/// # use nrd_sys::{Preset, ReblurSettings};
+/// let settings = ReblurSettings {
+///     blur_radius: 20.0,
+///     ..ReblurSettings::from_preset(Preset::Quality)
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Preset {
+    // Fewer passes and shorter history, for low-end hardware
+    Performance,
+
+    // NRD defaults
+    #[default]
+    Balanced,
+
+    // Longer history and more spatial filtering, at a higher cost
+    Quality,
+
+    // Maximum temporal accumulation with as little spatial filtering as possible, for static
+    // comparison shots
+    Reference,
+
+    // Tuned for the occlusion-only denoisers (*_OCCLUSION), which don't carry radiance
+    Occlusion,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 5] = [
+        Preset::Performance,
+        Preset::Balanced,
+        Preset::Quality,
+        Preset::Reference,
+        Preset::Occlusion,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::Performance => "Performance",
+            Preset::Balanced => "Balanced",
+            Preset::Quality => "Quality",
+            Preset::Reference => "Reference",
+            Preset::Occlusion => "Occlusion",
+        }
+    }
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl ReblurSettings {
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Performance => Self {
+                max_accumulated_frame_num: 30,
+                max_fast_accumulated_frame_num: 4,
+                history_fix_frame_num: 2,
+                diffuse_prepass_blur_radius: 0.0,
+                specular_prepass_blur_radius: 40.0,
+                use_prepass_only_for_specular_motion_estimation: true,
+                enable_performance_mode: true,
+                ..Default::default()
+            },
+            Preset::Balanced => Self::default(),
+            Preset::Quality => Self {
+                max_accumulated_frame_num: 60,
+                max_fast_accumulated_frame_num: 8,
+                history_fix_frame_num: 4,
+                blur_radius: 20.0,
+                enable_anti_firefly: true,
+                ..Default::default()
+            },
+            Preset::Reference => Self {
+                max_accumulated_frame_num: REBLUR_MAX_HISTORY_FRAME_NUM,
+                max_fast_accumulated_frame_num: REBLUR_MAX_HISTORY_FRAME_NUM,
+                history_fix_frame_num: 0,
+                enable_reference_accumulation: true,
+                ..Default::default()
+            },
+            // Occlusion is smooth and bounded, so firefly suppression and stabilization only add lag
+            Preset::Occlusion => Self {
+                max_accumulated_frame_num: 30,
+                max_fast_accumulated_frame_num: 6,
+                diffuse_prepass_blur_radius: 30.0,
+                specular_prepass_blur_radius: 30.0,
+                stabilization_strength: 0.0,
+                enable_anti_firefly: false,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// RELAX has no occlusion denoisers, so `Preset::Occlusion` maps to `Preset::Balanced` below.
+
+impl RelaxDiffuseSpecularSettings {
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Performance => Self {
+                diffuse_prepass_blur_radius: 0.0,
+                specular_prepass_blur_radius: 30.0,
+                diffuse_max_fast_accumulated_frame_num: 4,
+                specular_max_fast_accumulated_frame_num: 4,
+                history_fix_frame_num: 2,
+                atrous_iteration_num: 3,
+                ..Default::default()
+            },
+            Preset::Balanced | Preset::Occlusion => Self::default(),
+            Preset::Quality => Self {
+                diffuse_max_accumulated_frame_num: 60,
+                specular_max_accumulated_frame_num: 60,
+                diffuse_max_fast_accumulated_frame_num: 8,
+                specular_max_fast_accumulated_frame_num: 8,
+                history_fix_frame_num: 4,
+                atrous_iteration_num: 6,
+                enable_anti_firefly: true,
+                ..Default::default()
+            },
+            Preset::Reference => Self {
+                diffuse_prepass_blur_radius: 0.0,
+                specular_prepass_blur_radius: 0.0,
+                diffuse_max_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                specular_max_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                diffuse_max_fast_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                specular_max_fast_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                history_fix_frame_num: 0,
+                atrous_iteration_num: 2,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl RelaxDiffuseSettings {
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Performance => Self {
+                prepass_blur_radius: 0.0,
+                diffuse_max_fast_accumulated_frame_num: 4,
+                history_fix_frame_num: 2,
+                atrous_iteration_num: 3,
+                ..Default::default()
+            },
+            Preset::Balanced | Preset::Occlusion => Self::default(),
+            Preset::Quality => Self {
+                diffuse_max_accumulated_frame_num: 60,
+                diffuse_max_fast_accumulated_frame_num: 8,
+                history_fix_frame_num: 4,
+                atrous_iteration_num: 6,
+                enable_anti_firefly: true,
+                ..Default::default()
+            },
+            Preset::Reference => Self {
+                prepass_blur_radius: 0.0,
+                diffuse_max_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                diffuse_max_fast_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                history_fix_frame_num: 0,
+                atrous_iteration_num: 2,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl RelaxSpecularSettings {
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Performance => Self {
+                prepass_blur_radius: 30.0,
+                specular_max_fast_accumulated_frame_num: 4,
+                history_fix_frame_num: 2,
+                atrous_iteration_num: 3,
+                ..Default::default()
+            },
+            Preset::Balanced | Preset::Occlusion => Self::default(),
+            Preset::Quality => Self {
+                specular_max_accumulated_frame_num: 60,
+                specular_max_fast_accumulated_frame_num: 8,
+                history_fix_frame_num: 4,
+                atrous_iteration_num: 6,
+                enable_anti_firefly: true,
+                ..Default::default()
+            },
+            Preset::Reference => Self {
+                prepass_blur_radius: 0.0,
+                specular_max_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                specular_max_fast_accumulated_frame_num: RELAX_MAX_HISTORY_FRAME_NUM,
+                history_fix_frame_num: 0,
+                atrous_iteration_num: 2,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl SigmaSettings {
+    pub fn from_preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Performance => Self {
+                blur_radius_scale: 1.0,
+                ..Default::default()
+            },
+            Preset::Balanced | Preset::Occlusion => Self::default(),
+            Preset::Quality => Self {
+                blur_radius_scale: 2.5,
+                ..Default::default()
+            },
+            Preset::Reference => Self {
+                blur_radius_scale: 3.0,
+                ..Default::default()
+            },
+        }
+    }
+}
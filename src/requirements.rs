@@ -0,0 +1,209 @@
+//! Inputs, outputs and settings of every [`Denoiser`], as listed in the NRD header.
+
+use crate::ffi::{Denoiser, ResourceType};
+
+use ResourceType::*;
+
+/// The settings struct used by a group of denoisers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingsKind {
+    Reblur,
+    RelaxDiffuse,
+    RelaxSpecular,
+    RelaxDiffuseSpecular,
+    Sigma,
+    Reference,
+}
+
+impl Denoiser {
+    /// Textures that must be bound for the denoiser to run, including the common inputs
+    /// `IN_MV`, `IN_NORMAL_ROUGHNESS` and `IN_VIEWZ` where the denoiser uses them.
+    pub fn required_inputs(self) -> &'static [ResourceType] {
+        match self {
+            Denoiser::ReblurDiffuse | Denoiser::RelaxDiffuse => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_RADIANCE_HITDIST,
+            ],
+            Denoiser::ReblurDiffuseOcclusion => {
+                &[IN_MV, IN_NORMAL_ROUGHNESS, IN_VIEWZ, IN_DIFF_HITDIST]
+            }
+            Denoiser::ReblurDiffuseSh | Denoiser::RelaxDiffuseSh => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_SH0,
+                IN_DIFF_SH1,
+            ],
+            Denoiser::ReblurSpecular | Denoiser::RelaxSpecular => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_SPEC_RADIANCE_HITDIST,
+            ],
+            Denoiser::ReblurSpecularOcclusion | Denoiser::SpecularReflectionMv => {
+                &[IN_MV, IN_NORMAL_ROUGHNESS, IN_VIEWZ, IN_SPEC_HITDIST]
+            }
+            Denoiser::ReblurSpecularSh | Denoiser::RelaxSpecularSh => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_SPEC_SH0,
+                IN_SPEC_SH1,
+            ],
+            Denoiser::ReblurDiffuseSpecular | Denoiser::RelaxDiffuseSpecular => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_RADIANCE_HITDIST,
+                IN_SPEC_RADIANCE_HITDIST,
+            ],
+            Denoiser::ReblurDiffuseSpecularOcclusion => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_HITDIST,
+                IN_SPEC_HITDIST,
+            ],
+            Denoiser::ReblurDiffuseSpecularSh | Denoiser::RelaxDiffuseSpecularSh => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_SH0,
+                IN_DIFF_SH1,
+                IN_SPEC_SH0,
+                IN_SPEC_SH1,
+            ],
+            Denoiser::ReblurDiffuseDirectionalOcclusion => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_DIFF_DIRECTION_HITDIST,
+            ],
+            Denoiser::SigmaShadow => &[IN_MV, IN_NORMAL_ROUGHNESS, IN_VIEWZ, IN_SHADOWDATA],
+            Denoiser::SigmaShadowTranslucency => &[
+                IN_MV,
+                IN_NORMAL_ROUGHNESS,
+                IN_VIEWZ,
+                IN_SHADOWDATA,
+                IN_SHADOW_TRANSLUCENCY,
+            ],
+            Denoiser::Reference => &[IN_RADIANCE],
+            Denoiser::SpecularDeltaMv => &[IN_MV, IN_DELTA_PRIMARY_POS, IN_DELTA_SECONDARY_POS],
+        }
+    }
+
+    /// Textures the denoiser reads only if enabled in `CommonSettings`, e.g.
+    /// `is_history_confidence_available` for the confidence inputs.
+    pub fn optional_inputs(self) -> &'static [ResourceType] {
+        match self {
+            Denoiser::ReblurDiffuse
+            | Denoiser::ReblurDiffuseSh
+            | Denoiser::ReblurDiffuseDirectionalOcclusion
+            | Denoiser::RelaxDiffuse
+            | Denoiser::RelaxDiffuseSh => &[
+                IN_DIFF_CONFIDENCE,
+                IN_DISOCCLUSION_THRESHOLD_MIX,
+                IN_BASECOLOR_METALNESS,
+            ],
+            // The header also lists IN_SPEC_DIRECTION_PDF, which this library version lacks
+            Denoiser::ReblurSpecular
+            | Denoiser::ReblurSpecularSh
+            | Denoiser::RelaxSpecular
+            | Denoiser::RelaxSpecularSh => &[
+                IN_SPEC_CONFIDENCE,
+                IN_DISOCCLUSION_THRESHOLD_MIX,
+                IN_BASECOLOR_METALNESS,
+            ],
+            Denoiser::ReblurDiffuseSpecular
+            | Denoiser::ReblurDiffuseSpecularSh
+            | Denoiser::RelaxDiffuseSpecular
+            | Denoiser::RelaxDiffuseSpecularSh => &[
+                IN_DIFF_CONFIDENCE,
+                IN_SPEC_CONFIDENCE,
+                IN_DISOCCLUSION_THRESHOLD_MIX,
+                IN_BASECOLOR_METALNESS,
+            ],
+            Denoiser::ReblurDiffuseOcclusion
+            | Denoiser::ReblurSpecularOcclusion
+            | Denoiser::ReblurDiffuseSpecularOcclusion => {
+                &[IN_DISOCCLUSION_THRESHOLD_MIX, IN_BASECOLOR_METALNESS]
+            }
+            _ => &[],
+        }
+    }
+
+    /// Textures written by the denoiser. They may be used as history and must be kept between
+    /// frames, e.g. SIGMA reads `OUT_SHADOW_TRANSLUCENCY` of the previous frame. The exception is
+    /// `OUT_VALIDATION`, which is only written if `CommonSettings::enable_validation` is set.
+    pub fn outputs(self) -> &'static [ResourceType] {
+        match self {
+            Denoiser::ReblurDiffuse | Denoiser::RelaxDiffuse => {
+                &[OUT_DIFF_RADIANCE_HITDIST, OUT_VALIDATION]
+            }
+            Denoiser::ReblurDiffuseOcclusion => &[OUT_DIFF_HITDIST, OUT_VALIDATION],
+            Denoiser::ReblurDiffuseSh | Denoiser::RelaxDiffuseSh => {
+                &[OUT_DIFF_SH0, OUT_DIFF_SH1, OUT_VALIDATION]
+            }
+            Denoiser::ReblurSpecular | Denoiser::RelaxSpecular => {
+                &[OUT_SPEC_RADIANCE_HITDIST, OUT_VALIDATION]
+            }
+            Denoiser::ReblurSpecularOcclusion => &[OUT_SPEC_HITDIST, OUT_VALIDATION],
+            Denoiser::ReblurSpecularSh | Denoiser::RelaxSpecularSh => {
+                &[OUT_SPEC_SH0, OUT_SPEC_SH1, OUT_VALIDATION]
+            }
+            Denoiser::ReblurDiffuseSpecular | Denoiser::RelaxDiffuseSpecular => &[
+                OUT_DIFF_RADIANCE_HITDIST,
+                OUT_SPEC_RADIANCE_HITDIST,
+                OUT_VALIDATION,
+            ],
+            Denoiser::ReblurDiffuseSpecularOcclusion => {
+                &[OUT_DIFF_HITDIST, OUT_SPEC_HITDIST, OUT_VALIDATION]
+            }
+            Denoiser::ReblurDiffuseSpecularSh | Denoiser::RelaxDiffuseSpecularSh => &[
+                OUT_DIFF_SH0,
+                OUT_DIFF_SH1,
+                OUT_SPEC_SH0,
+                OUT_SPEC_SH1,
+                OUT_VALIDATION,
+            ],
+            Denoiser::ReblurDiffuseDirectionalOcclusion => {
+                &[OUT_DIFF_DIRECTION_HITDIST, OUT_VALIDATION]
+            }
+            Denoiser::SigmaShadow | Denoiser::SigmaShadowTranslucency => {
+                &[OUT_SHADOW_TRANSLUCENCY, OUT_VALIDATION]
+            }
+            Denoiser::Reference => &[OUT_RADIANCE],
+            Denoiser::SpecularReflectionMv => &[OUT_REFLECTION_MV],
+            Denoiser::SpecularDeltaMv => &[OUT_DELTA_MV],
+        }
+    }
+
+    /// The settings struct passed to `set_denoiser_settings`, `None` for the motion vector
+    /// denoisers which have no settings.
+    pub fn settings_kind(self) -> Option<SettingsKind> {
+        match self {
+            Denoiser::ReblurDiffuse
+            | Denoiser::ReblurDiffuseOcclusion
+            | Denoiser::ReblurDiffuseSh
+            | Denoiser::ReblurSpecular
+            | Denoiser::ReblurSpecularOcclusion
+            | Denoiser::ReblurSpecularSh
+            | Denoiser::ReblurDiffuseSpecular
+            | Denoiser::ReblurDiffuseSpecularOcclusion
+            | Denoiser::ReblurDiffuseSpecularSh
+            | Denoiser::ReblurDiffuseDirectionalOcclusion => Some(SettingsKind::Reblur),
+            Denoiser::RelaxDiffuse | Denoiser::RelaxDiffuseSh => Some(SettingsKind::RelaxDiffuse),
+            Denoiser::RelaxSpecular | Denoiser::RelaxSpecularSh => {
+                Some(SettingsKind::RelaxSpecular)
+            }
+            Denoiser::RelaxDiffuseSpecular | Denoiser::RelaxDiffuseSpecularSh => {
+                Some(SettingsKind::RelaxDiffuseSpecular)
+            }
+            Denoiser::SigmaShadow | Denoiser::SigmaShadowTranslucency => Some(SettingsKind::Sigma),
+            Denoiser::Reference => Some(SettingsKind::Reference),
+            Denoiser::SpecularReflectionMv | Denoiser::SpecularDeltaMv => None,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ffi::Sampler;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SamplerFilter {
+    Nearest,
+    Linear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SamplerAddressMode {
+    ClampToEdge,
+    MirroredRepeat,
+}
+
+/// Backend-neutral description of a [`Sampler`], matching the samplers created by the NRD
+/// integration layer. Anisotropic filtering and depth comparison are always off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SamplerDesc {
+    pub min_filter: SamplerFilter,
+    pub mag_filter: SamplerFilter,
+    pub mipmap_filter: SamplerFilter,
+    // Used for the U, V and W coordinates
+    pub address_mode: SamplerAddressMode,
+    pub min_lod: f32,
+    pub max_lod: f32,
+}
+
+impl Sampler {
+    pub fn desc(self) -> SamplerDesc {
+        let (filter, address_mode) = match self {
+            Sampler::NearestClamp => (SamplerFilter::Nearest, SamplerAddressMode::ClampToEdge),
+            Sampler::NearestMirroredRepeat => {
+                (SamplerFilter::Nearest, SamplerAddressMode::MirroredRepeat)
+            }
+            Sampler::LinearClamp => (SamplerFilter::Linear, SamplerAddressMode::ClampToEdge),
+            Sampler::LinearMirroredRepeat => {
+                (SamplerFilter::Linear, SamplerAddressMode::MirroredRepeat)
+            }
+        };
+        SamplerDesc {
+            min_filter: filter,
+            mag_filter: filter,
+            mipmap_filter: SamplerFilter::Nearest,
+            address_mode,
+            min_lod: 0.0,
+            max_lod: 16.0,
+        }
+    }
+}
+
+impl From<Sampler> for SamplerDesc {
+    fn from(sampler: Sampler) -> Self {
+        sampler.desc()
+    }
+}
+
+impl SamplerDesc {
+    #[cfg(feature = "ash")]
+    pub fn to_vk(&self) -> ash::vk::SamplerCreateInfo<'static> {
+        use ash::vk;
+        let filter = |filter| match filter {
+            SamplerFilter::Nearest => vk::Filter::NEAREST,
+            SamplerFilter::Linear => vk::Filter::LINEAR,
+        };
+        let address_mode = match self.address_mode {
+            SamplerAddressMode::ClampToEdge => vk::SamplerAddressMode::CLAMP_TO_EDGE,
+            SamplerAddressMode::MirroredRepeat => vk::SamplerAddressMode::MIRRORED_REPEAT,
+        };
+        vk::SamplerCreateInfo::default()
+            .min_filter(filter(self.min_filter))
+            .mag_filter(filter(self.mag_filter))
+            .mipmap_mode(match self.mipmap_filter {
+                SamplerFilter::Nearest => vk::SamplerMipmapMode::NEAREST,
+                SamplerFilter::Linear => vk::SamplerMipmapMode::LINEAR,
+            })
+            .address_mode_u(address_mode)
+            .address_mode_v(address_mode)
+            .address_mode_w(address_mode)
+            .anisotropy_enable(false)
+            .max_anisotropy(1.0)
+            .compare_enable(false)
+            .min_lod(self.min_lod)
+            .max_lod(self.max_lod)
+    }
+
+    #[cfg(feature = "wgpu-types")]
+    pub fn to_wgpu(&self) -> wgpu_types::SamplerDescriptor<Option<&'static str>> {
+        use wgpu_types::{AddressMode, FilterMode};
+        let filter = |filter| match filter {
+            SamplerFilter::Nearest => FilterMode::Nearest,
+            SamplerFilter::Linear => FilterMode::Linear,
+        };
+        let address_mode = match self.address_mode {
+            SamplerAddressMode::ClampToEdge => AddressMode::ClampToEdge,
+            SamplerAddressMode::MirroredRepeat => AddressMode::MirrorRepeat,
+        };
+        wgpu_types::SamplerDescriptor {
+            label: None,
+            address_mode_u: address_mode,
+            address_mode_v: address_mode,
+            address_mode_w: address_mode,
+            mag_filter: filter(self.mag_filter),
+            min_filter: filter(self.min_filter),
+            mipmap_filter: filter(self.mipmap_filter),
+            lod_min_clamp: self.min_lod,
+            lod_max_clamp: self.max_lod,
+            compare: None,
+            anisotropy_clamp: 1,
+            border_color: None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::ffi::{
+    self, CommonSettings, Identifier, ReblurSettings, ReferenceSettings, RelaxDiffuseSettings,
+    RelaxDiffuseSpecularSettings, RelaxSpecularSettings, SigmaSettings,
+};
+use crate::{Instance, SettingsKind, Validate, Violation};
+
+/// Settings for any denoiser, for code that handles denoisers generically.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AnyDenoiserSettings {
+    Reblur(ReblurSettings),
+    RelaxDiffuse(RelaxDiffuseSettings),
+    RelaxSpecular(RelaxSpecularSettings),
+    RelaxDiffuseSpecular(RelaxDiffuseSpecularSettings),
+    Sigma(SigmaSettings),
+    Reference(ReferenceSettings),
+}
+
+impl AnyDenoiserSettings {
+    pub fn kind(&self) -> SettingsKind {
+        match self {
+            AnyDenoiserSettings::Reblur(_) => SettingsKind::Reblur,
+            AnyDenoiserSettings::RelaxDiffuse(_) => SettingsKind::RelaxDiffuse,
+            AnyDenoiserSettings::RelaxSpecular(_) => SettingsKind::RelaxSpecular,
+            AnyDenoiserSettings::RelaxDiffuseSpecular(_) => SettingsKind::RelaxDiffuseSpecular,
+            AnyDenoiserSettings::Sigma(_) => SettingsKind::Sigma,
+            AnyDenoiserSettings::Reference(_) => SettingsKind::Reference,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Vec<Violation>> {
+        match self {
+            AnyDenoiserSettings::Reblur(settings) => settings.validate(),
+            AnyDenoiserSettings::RelaxDiffuse(settings) => settings.validate(),
+            AnyDenoiserSettings::RelaxSpecular(settings) => settings.validate(),
+            AnyDenoiserSettings::RelaxDiffuseSpecular(settings) => settings.validate(),
+            AnyDenoiserSettings::Sigma(settings) => settings.validate(),
+            AnyDenoiserSettings::Reference(settings) => settings.validate(),
+        }
+    }
+
+    /// Forwards the settings to [`Instance::set_denoiser_settings`].
+    pub fn apply(
+        &self,
+        instance: &mut Instance,
+        identifier: Identifier,
+    ) -> Result<(), ffi::Result> {
+        match self {
+            AnyDenoiserSettings::Reblur(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+            AnyDenoiserSettings::RelaxDiffuse(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+            AnyDenoiserSettings::RelaxSpecular(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+            AnyDenoiserSettings::RelaxDiffuseSpecular(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+            AnyDenoiserSettings::Sigma(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+            AnyDenoiserSettings::Reference(settings) => {
+                instance.set_denoiser_settings(identifier, settings)
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_settings {
+    ($($variant: ident($ty: ty)),+) => {
+        $(
+            impl From<$ty> for AnyDenoiserSettings {
+                fn from(settings: $ty) -> Self {
+                    AnyDenoiserSettings::$variant(settings)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_settings!(
+    Reblur(ReblurSettings),
+    RelaxDiffuse(RelaxDiffuseSettings),
+    RelaxSpecular(RelaxSpecularSettings),
+    RelaxDiffuseSpecular(RelaxDiffuseSpecularSettings),
+    Sigma(SigmaSettings),
+    Reference(ReferenceSettings)
+);
+
+/// Settings that reached NRD since the last [`Instance::take_changes`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SettingsChanges {
+    // A non-per-frame field of `CommonSettings` changed
+    pub common: bool,
+
+    // Denoisers whose settings changed, in the order they were set
+    pub denoisers: Vec<Identifier>,
+}
+
+impl SettingsChanges {
+    pub fn is_empty(&self) -> bool {
+        !self.common && self.denoisers.is_empty()
+    }
+}
+
+// Compares the `CommonSettings` fields that stay the same from frame to frame, ignoring camera
+// matrices (including the world-space motion matrix), jitter, resolution scale, time delta, frame
+// index and accumulation mode
+pub(crate) fn tuned_common_settings_eq(a: &CommonSettings, b: &CommonSettings) -> bool {
+    a.motion_vector_scale == b.motion_vector_scale
+        && a.denoising_range == b.denoising_range
+        && a.disocclusion_threshold == b.disocclusion_threshold
+        && a.disocclusion_threshold_alternate == b.disocclusion_threshold_alternate
+        && a.split_screen == b.split_screen
+        && a.debug == b.debug
+        && a.input_subrect_origin == b.input_subrect_origin
+        && a.is_motion_vector_in_world_space == b.is_motion_vector_in_world_space
+        && a.is_history_confidence_available == b.is_history_confidence_available
+        && a.is_disocclusion_threshold_mix_available == b.is_disocclusion_threshold_mix_available
+        && a.is_base_color_metalness_available == b.is_base_color_metalness_available
+        && a.enable_validation == b.enable_validation
+}
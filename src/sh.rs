@@ -0,0 +1,114 @@
+//! Spherical harmonics data used by the `*Sh` denoisers: IN_*_SH0 / IN_*_SH1 store the radiance
+//! as YCoCg with the hit distance, and the luminance weighted direction of the sample.
+
+use crate::packing::{self, EPS};
+
+/// `REBLUR_FrontEnd_PackSh`, returns the IN_*_SH0 and IN_*_SH1 texels.
+///
+/// `norm_hit_dist` is the output of
+/// [`HitDistanceParameters::normalize`](crate::HitDistanceParameters::normalize), `direction` is
+/// the normalized direction of the sample.
+pub fn reblur_pack(
+    radiance: [f32; 3],
+    norm_hit_dist: f32,
+    direction: [f32; 3],
+    sanitize: bool,
+) -> [[f32; 4]; 2] {
+    let sh0 = packing::reblur_pack_radiance_and_norm_hit_dist(radiance, norm_hit_dist, sanitize);
+    [sh0, pack_direction(direction, sh0[0])]
+}
+
+/// `RELAX_FrontEnd_PackSh`, returns the IN_*_SH0 and IN_*_SH1 texels.
+pub fn relax_pack(
+    radiance: [f32; 3],
+    hit_dist: f32,
+    direction: [f32; 3],
+    sanitize: bool,
+) -> [[f32; 4]; 2] {
+    let [r, g, b, hit_dist] =
+        packing::relax_pack_radiance_and_hit_dist(radiance, hit_dist, sanitize);
+    let [y, co, cg] = packing::linear_to_ycocg([r, g, b]);
+    [[y, co, cg, hit_dist], pack_direction(direction, y)]
+}
+
+fn pack_direction(direction: [f32; 3], luminance: f32) -> [f32; 4] {
+    let [x, y, z] = direction.map(|c| c * luminance);
+    [x, y, z, 0.0]
+}
+
+/// Decoded SH0 / SH1 texel pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sg {
+    // Luminance (Y of YCoCg)
+    pub c0: f32,
+
+    // Co and Cg of YCoCg
+    pub chroma: [f32; 2],
+
+    // REBLUR: normalized hit distance, RELAX: hit distance
+    pub hit_dist: f32,
+
+    // Luminance weighted direction
+    pub c1: [f32; 3],
+}
+
+impl Sg {
+    /// `REBLUR_BackEnd_UnpackSh` and `RELAX_BackEnd_UnpackSh`
+    pub fn unpack(sh0: [f32; 4], sh1: [f32; 4]) -> Self {
+        Self {
+            c0: sh0[0],
+            chroma: [sh0[1], sh0[2]],
+            hit_dist: sh0[3],
+            c1: [sh1[0], sh1[1], sh1[2]],
+        }
+    }
+
+    /// `NRD_SG_ExtractColor`, the radiance integrated over all directions.
+    pub fn color(&self) -> [f32; 3] {
+        packing::ycocg_to_linear([self.c0, self.chroma[0], self.chroma[1]])
+    }
+
+    /// `NRD_SG_ExtractDirection`, the dominant direction of the signal.
+    pub fn direction(&self) -> [f32; 3] {
+        packing::safe_normalize(self.c1)
+    }
+
+    /// `NRD_SH_ResolveDiffuse`, irradiance for a surface with the normal `normal`, reconstructed
+    /// from the L1 data with the non-linear "Geomerics" method to avoid ringing.
+    pub fn resolve_diffuse(&self, normal: [f32; 3]) -> [f32; 3] {
+        let r0 = self.c0.max(0.0);
+        let length = dot(self.c1, self.c1).sqrt();
+        let r = (length / (r0 + EPS)).min(1.0 - EPS);
+        let q = 0.5 + 0.5 * dot(normal, self.c1) / length.max(EPS);
+        let p = 1.0 + 2.0 * r;
+        let a = (1.0 - r) / (1.0 + r);
+        let y = r0 * (a + (1.0 - a) * (p + 1.0) * q.max(0.0).powf(p));
+        self.corrected_color(y)
+    }
+
+    // _NRD_YCoCgToLinear_Corrected
+    fn corrected_color(&self, y: f32) -> [f32; 3] {
+        let y = y.max(0.0);
+        let scale = (y + EPS) / (self.c0 + EPS);
+        packing::ycocg_to_linear([y, self.chroma[0] * scale, self.chroma[1] * scale])
+    }
+}
+
+/// Resolves OUT_*_SH0 / OUT_*_SH1 images into diffuse irradiance, using the unpacked normals
+/// from IN_NORMAL_ROUGHNESS.
+pub fn resolve_diffuse_image(
+    sh0: &[[f32; 4]],
+    sh1: &[[f32; 4]],
+    normals: &[[f32; 3]],
+) -> Vec<[f32; 3]> {
+    assert!(sh0.len() == sh1.len() && sh0.len() == normals.len());
+    sh0.iter()
+        .zip(sh1)
+        .zip(normals)
+        .map(|((&sh0, &sh1), &normal)| Sg::unpack(sh0, sh1).resolve_diffuse(normal))
+        .collect()
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
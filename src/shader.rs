@@ -0,0 +1,52 @@
+//! Shader versions of the functions in [`crate::packing`] and [`crate::sh`], to include in the
+//! shaders writing denoiser inputs or reading denoiser outputs.
+
+use crate::ffi::{LibraryDesc, NormalEncoding, RoughnessEncoding};
+
+pub const HLSL: &str = include_str!("../shaders/NRD.hlsli");
+pub const GLSL: &str = include_str!("../shaders/NRD.glsl");
+pub const WGSL: &str = include_str!("../shaders/NRD.wgsl");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderLanguage {
+    Hlsl,
+    Glsl,
+    Wgsl,
+}
+
+impl ShaderLanguage {
+    /// The include file, which expects `NRD_NORMAL_ENCODING` and `NRD_ROUGHNESS_ENCODING` to be
+    /// defined by the includer.
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::Hlsl => HLSL,
+            Self::Glsl => GLSL,
+            Self::Wgsl => WGSL,
+        }
+    }
+
+    /// Declarations of `NRD_NORMAL_ENCODING` and `NRD_ROUGHNESS_ENCODING`.
+    pub fn defines(
+        self,
+        normal_encoding: NormalEncoding,
+        roughness_encoding: RoughnessEncoding,
+    ) -> String {
+        let normal_encoding = normal_encoding as u8;
+        let roughness_encoding = roughness_encoding as u8;
+        match self {
+            Self::Hlsl | Self::Glsl => format!(
+                "#define NRD_NORMAL_ENCODING {normal_encoding}\n\
+                 #define NRD_ROUGHNESS_ENCODING {roughness_encoding}\n"
+            ),
+            Self::Wgsl => format!(
+                "const NRD_NORMAL_ENCODING: u32 = {normal_encoding}u;\n\
+                 const NRD_ROUGHNESS_ENCODING: u32 = {roughness_encoding}u;\n"
+            ),
+        }
+    }
+
+    /// The include file with the encodings the library was built with.
+    pub fn include(self, library: &LibraryDesc) -> String {
+        self.defines(library.normal_encoding, library.roughness_encoding) + self.source()
+    }
+}
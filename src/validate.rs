@@ -0,0 +1,671 @@
+use std::fmt::Display;
+
+use crate::ffi::{
+    self, CheckerboardMode, CommonSettings, HitDistanceParameters, HitDistanceReconstructionMode,
+    ReblurAntilagSettings, ReblurSettings, ReferenceSettings, RelaxAntilagSettings,
+    RelaxDiffuseSettings, RelaxDiffuseSpecularSettings, RelaxSpecularSettings, SigmaSettings,
+    REBLUR_MAX_HISTORY_FRAME_NUM, RELAX_MAX_HISTORY_FRAME_NUM,
+};
+
+// NRD_FP16_MAX / NRD_FP16_VIEWZ_SCALE - 1
+const MAX_DENOISING_RANGE: f32 = 524031.0;
+
+/// A settings field outside of the range documented in the NRD headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    // Path of the offending field, e.g. "hit_distance_parameters.b"
+    pub field: String,
+    pub reason: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+/// Range checks for settings structs, run before handing them to NRD.
+pub trait Validate {
+    /// Returns every violation found, not just the first one.
+    fn validate(&self) -> Result<(), Vec<Violation>>;
+}
+
+pub(crate) trait Check {
+    fn check(&self, checker: &mut Checker);
+}
+
+impl<T: Check> Validate for T {
+    fn validate(&self) -> Result<(), Vec<Violation>> {
+        let mut checker = Checker::default();
+        self.check(&mut checker);
+        if checker.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(checker.violations)
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Checker {
+    prefix: String,
+    violations: Vec<Violation>,
+}
+
+impl Checker {
+    fn fail(&mut self, field: &str, reason: String) {
+        self.violations.push(Violation {
+            field: format!("{}{}", self.prefix, field),
+            reason,
+        });
+    }
+
+    fn nested(&mut self, field: &str, value: &impl Check) {
+        let len = self.prefix.len();
+        self.prefix.push_str(field);
+        self.prefix.push('.');
+        value.check(self);
+        self.prefix.truncate(len);
+    }
+
+    fn finite(&mut self, field: &str, value: f32) -> bool {
+        if !value.is_finite() {
+            self.fail(field, format!("{value} is not finite"));
+        }
+        value.is_finite()
+    }
+
+    fn finite_all(&mut self, field: &str, values: &[f32]) {
+        if let Some(value) = values.iter().find(|v| !v.is_finite()) {
+            self.fail(field, format!("contains {value}"));
+        }
+    }
+
+    // [min; max]
+    fn range(&mut self, field: &str, value: f32, min: f32, max: f32) {
+        if self.finite(field, value) && !(min..=max).contains(&value) {
+            self.fail(field, format!("{value} is outside of [{min}; {max}]"));
+        }
+    }
+
+    fn min(&mut self, field: &str, value: f32, min: f32) {
+        if self.finite(field, value) && value < min {
+            self.fail(field, format!("{value} must be >= {min}"));
+        }
+    }
+
+    fn max(&mut self, field: &str, value: f32, max: f32) {
+        if self.finite(field, value) && value > max {
+            self.fail(field, format!("{value} must be <= {max}"));
+        }
+    }
+
+    // (min; max]
+    fn range_exclusive_min(&mut self, field: &str, value: f32, min: f32, max: f32) {
+        if self.finite(field, value) && !(value > min && value <= max) {
+            self.fail(field, format!("{value} is outside of ({min}; {max}]"));
+        }
+    }
+
+    fn positive(&mut self, field: &str, value: f32) {
+        if self.finite(field, value) && value <= 0.0 {
+            self.fail(field, format!("{value} must be > 0"));
+        }
+    }
+
+    fn frame_num(&mut self, field: &str, value: u32, max: u32) {
+        if value > max {
+            self.fail(field, format!("{value} is outside of [0; {max}]"));
+        }
+    }
+
+    fn frame_num_order(&mut self, field: &str, value: u32, other_field: &str, other: u32) {
+        if value > other {
+            self.fail(
+                field,
+                format!("{value} must not exceed \"{other_field}\" ({other})"),
+            );
+        }
+    }
+
+    fn atrous_iteration_num(&mut self, value: u32) {
+        if !(2..=8).contains(&value) {
+            self.fail(
+                "atrous_iteration_num",
+                format!("{value} is outside of [2; 8]"),
+            );
+        }
+    }
+
+    fn checkerboard(
+        &mut self,
+        checkerboard_mode: CheckerboardMode,
+        hit_distance_reconstruction_mode: HitDistanceReconstructionMode,
+    ) {
+        if checkerboard_mode != CheckerboardMode::Off
+            && hit_distance_reconstruction_mode != HitDistanceReconstructionMode::Off
+        {
+            self.fail(
+                "hit_distance_reconstruction_mode",
+                "must be Off when checkerboard_mode is used, reconstruction is only for probabilistic sampling".into(),
+            );
+        }
+    }
+}
+
+impl Check for CommonSettings {
+    fn check(&self, c: &mut Checker) {
+        c.finite_all("view_to_clip_matrix", &self.view_to_clip_matrix);
+        c.finite_all("view_to_clip_matrix_prev", &self.view_to_clip_matrix_prev);
+        c.finite_all("world_to_view_matrix", &self.world_to_view_matrix);
+        c.finite_all("world_to_view_matrix_prev", &self.world_to_view_matrix_prev);
+        c.finite_all(
+            "world_prev_to_world_matrix",
+            &self.world_prev_to_world_matrix,
+        );
+        c.finite_all("motion_vector_scale", &self.motion_vector_scale);
+        for (field, jitter) in [
+            ("camera_jitter", self.camera_jitter),
+            ("camera_jitter_prev", self.camera_jitter_prev),
+        ] {
+            for value in jitter {
+                c.range(field, value, -0.5, 0.5);
+            }
+        }
+        for (field, scale) in [
+            ("resolution_scale", self.resolution_scale),
+            ("resolution_scale_prev", self.resolution_scale_prev),
+        ] {
+            for value in scale {
+                c.range_exclusive_min(field, value, 0.0, 1.0);
+            }
+        }
+        c.min(
+            "time_delta_between_frames",
+            self.time_delta_between_frames,
+            0.0,
+        );
+        c.range_exclusive_min(
+            "denoising_range",
+            self.denoising_range,
+            0.0,
+            MAX_DENOISING_RANGE,
+        );
+        c.range(
+            "disocclusion_threshold",
+            self.disocclusion_threshold,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "disocclusion_threshold_alternate",
+            self.disocclusion_threshold_alternate,
+            0.0,
+            1.0,
+        );
+        c.range("split_screen", self.split_screen, 0.0, 1.0);
+    }
+}
+
+impl Check for HitDistanceParameters {
+    fn check(&self, c: &mut Checker) {
+        c.min("a", self.a, 0.0);
+        c.positive("b", self.b);
+        c.min("c", self.c, 1.0);
+        c.max("d", self.d, 0.0);
+    }
+}
+
+impl Check for ReblurAntilagSettings {
+    fn check(&self, c: &mut Checker) {
+        c.range(
+            "luminance_sigma_scale",
+            self.luminance_sigma_scale,
+            1.0,
+            3.0,
+        );
+        c.range(
+            "hit_distance_sigma_scale",
+            self.hit_distance_sigma_scale,
+            1.0,
+            3.0,
+        );
+        for (field, value) in [
+            ("luminance_antilag_power", self.luminance_antilag_power),
+            (
+                "hit_distance_antilag_power",
+                self.hit_distance_antilag_power,
+            ),
+        ] {
+            c.range_exclusive_min(field, value, 0.0, 1.0);
+        }
+    }
+}
+
+impl Check for ReblurSettings {
+    fn check(&self, c: &mut Checker) {
+        c.nested("hit_distance_parameters", &self.hit_distance_parameters);
+        c.nested("antilag_settings", &self.antilag_settings);
+
+        c.frame_num(
+            "max_accumulated_frame_num",
+            self.max_accumulated_frame_num,
+            REBLUR_MAX_HISTORY_FRAME_NUM,
+        );
+        c.frame_num(
+            "max_fast_accumulated_frame_num",
+            self.max_fast_accumulated_frame_num,
+            REBLUR_MAX_HISTORY_FRAME_NUM,
+        );
+        c.frame_num(
+            "history_fix_frame_num",
+            self.history_fix_frame_num,
+            REBLUR_MAX_HISTORY_FRAME_NUM,
+        );
+        c.frame_num_order(
+            "max_fast_accumulated_frame_num",
+            self.max_fast_accumulated_frame_num,
+            "max_accumulated_frame_num",
+            self.max_accumulated_frame_num,
+        );
+        c.frame_num_order(
+            "history_fix_frame_num",
+            self.history_fix_frame_num,
+            "max_fast_accumulated_frame_num",
+            self.max_fast_accumulated_frame_num,
+        );
+
+        c.min(
+            "diffuse_prepass_blur_radius",
+            self.diffuse_prepass_blur_radius,
+            0.0,
+        );
+        c.min(
+            "specular_prepass_blur_radius",
+            self.specular_prepass_blur_radius,
+            0.0,
+        );
+        c.min("blur_radius", self.blur_radius, 0.0);
+        c.min(
+            "history_fix_stride_between_samples",
+            self.history_fix_stride_between_samples,
+            0.0,
+        );
+        c.range("lobe_angle_fraction", self.lobe_angle_fraction, 0.0, 1.0);
+        c.range("roughness_fraction", self.roughness_fraction, 0.0, 1.0);
+        c.range(
+            "responsive_accumulation_roughness_threshold",
+            self.responsive_accumulation_roughness_threshold,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "stabilization_strength",
+            self.stabilization_strength,
+            0.0,
+            1.0,
+        );
+        c.min(
+            "plane_distance_sensitivity",
+            self.plane_distance_sensitivity,
+            0.0,
+        );
+
+        let [lo, hi] = self.specular_probability_thresholds_for_mv_modification;
+        c.range(
+            "specular_probability_thresholds_for_mv_modification",
+            lo,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "specular_probability_thresholds_for_mv_modification",
+            hi,
+            0.0,
+            1.0,
+        );
+        if lo > hi {
+            c.fail(
+                "specular_probability_thresholds_for_mv_modification",
+                format!("[{lo}, {hi}] must be in ascending order"),
+            );
+        }
+
+        c.checkerboard(
+            self.checkerboard_mode,
+            self.hit_distance_reconstruction_mode,
+        );
+    }
+}
+
+impl Check for SigmaSettings {
+    fn check(&self, c: &mut Checker) {
+        c.min(
+            "plane_distance_sensitivity",
+            self.plane_distance_sensitivity,
+            0.0,
+        );
+        c.range("blur_radius_scale", self.blur_radius_scale, 1.0, 3.0);
+    }
+}
+
+impl Check for ReferenceSettings {
+    fn check(&self, _: &mut Checker) {}
+}
+
+impl Check for RelaxAntilagSettings {
+    fn check(&self, c: &mut Checker) {
+        c.range("acceleration_amount", self.acceleration_amount, 0.0, 1.0);
+        c.positive("spatial_sigma_scale", self.spatial_sigma_scale);
+        c.positive("temporal_sigma_scale", self.temporal_sigma_scale);
+        c.range("reset_amount", self.reset_amount, 0.0, 1.0);
+    }
+}
+
+// Shared by the three RELAX settings structs
+struct RelaxCommon<'a> {
+    antilag_settings: &'a RelaxAntilagSettings,
+    history_fix_frame_num: u32,
+    history_fix_edge_stopping_normal_power: f32,
+    history_fix_stride_between_samples: f32,
+    history_clamping_color_box_sigma_scale: f32,
+    atrous_iteration_num: u32,
+    depth_threshold: f32,
+    confidence_driven_relaxation_multiplier: f32,
+    confidence_driven_luminance_edge_stopping_relaxation: f32,
+    confidence_driven_normal_edge_stopping_relaxation: f32,
+    checkerboard_mode: CheckerboardMode,
+    hit_distance_reconstruction_mode: HitDistanceReconstructionMode,
+}
+
+macro_rules! relax_common {
+    ($settings: expr) => {
+        RelaxCommon {
+            antilag_settings: &$settings.antilag_settings,
+            history_fix_frame_num: $settings.history_fix_frame_num,
+            history_fix_edge_stopping_normal_power: $settings
+                .history_fix_edge_stopping_normal_power,
+            history_fix_stride_between_samples: $settings.history_fix_stride_between_samples,
+            history_clamping_color_box_sigma_scale: $settings
+                .history_clamping_color_box_sigma_scale,
+            atrous_iteration_num: $settings.atrous_iteration_num,
+            depth_threshold: $settings.depth_threshold,
+            confidence_driven_relaxation_multiplier: $settings
+                .confidence_driven_relaxation_multiplier,
+            confidence_driven_luminance_edge_stopping_relaxation: $settings
+                .confidence_driven_luminance_edge_stopping_relaxation,
+            confidence_driven_normal_edge_stopping_relaxation: $settings
+                .confidence_driven_normal_edge_stopping_relaxation,
+            checkerboard_mode: $settings.checkerboard_mode,
+            hit_distance_reconstruction_mode: $settings.hit_distance_reconstruction_mode,
+        }
+    };
+}
+
+impl RelaxCommon<'_> {
+    fn check(&self, c: &mut Checker) {
+        c.nested("antilag_settings", self.antilag_settings);
+        c.frame_num(
+            "history_fix_frame_num",
+            self.history_fix_frame_num,
+            RELAX_MAX_HISTORY_FRAME_NUM,
+        );
+        c.positive(
+            "history_fix_edge_stopping_normal_power",
+            self.history_fix_edge_stopping_normal_power,
+        );
+        c.min(
+            "history_fix_stride_between_samples",
+            self.history_fix_stride_between_samples,
+            0.0,
+        );
+        c.range(
+            "history_clamping_color_box_sigma_scale",
+            self.history_clamping_color_box_sigma_scale,
+            1.0,
+            3.0,
+        );
+        c.atrous_iteration_num(self.atrous_iteration_num);
+        c.min("depth_threshold", self.depth_threshold, 0.0);
+        c.min(
+            "confidence_driven_relaxation_multiplier",
+            self.confidence_driven_relaxation_multiplier,
+            0.0,
+        );
+        c.min(
+            "confidence_driven_luminance_edge_stopping_relaxation",
+            self.confidence_driven_luminance_edge_stopping_relaxation,
+            0.0,
+        );
+        c.min(
+            "confidence_driven_normal_edge_stopping_relaxation",
+            self.confidence_driven_normal_edge_stopping_relaxation,
+            0.0,
+        );
+        c.checkerboard(
+            self.checkerboard_mode,
+            self.hit_distance_reconstruction_mode,
+        );
+    }
+}
+
+// Accumulation limits of one RELAX signal
+fn check_relax_history(
+    c: &mut Checker,
+    prefix: &str,
+    max_accumulated_frame_num: u32,
+    max_fast_accumulated_frame_num: u32,
+    history_fix_frame_num: u32,
+) {
+    let max_field = format!("{prefix}max_accumulated_frame_num");
+    let fast_field = format!("{prefix}max_fast_accumulated_frame_num");
+    c.frame_num(
+        &max_field,
+        max_accumulated_frame_num,
+        RELAX_MAX_HISTORY_FRAME_NUM,
+    );
+    c.frame_num(
+        &fast_field,
+        max_fast_accumulated_frame_num,
+        RELAX_MAX_HISTORY_FRAME_NUM,
+    );
+    c.frame_num_order(
+        &fast_field,
+        max_fast_accumulated_frame_num,
+        &max_field,
+        max_accumulated_frame_num,
+    );
+    c.frame_num_order(
+        "history_fix_frame_num",
+        history_fix_frame_num,
+        &fast_field,
+        max_fast_accumulated_frame_num,
+    );
+}
+
+impl Check for RelaxDiffuseSpecularSettings {
+    fn check(&self, c: &mut Checker) {
+        relax_common!(self).check(c);
+        check_relax_history(
+            c,
+            "diffuse_",
+            self.diffuse_max_accumulated_frame_num,
+            self.diffuse_max_fast_accumulated_frame_num,
+            self.history_fix_frame_num,
+        );
+        check_relax_history(
+            c,
+            "specular_",
+            self.specular_max_accumulated_frame_num,
+            self.specular_max_fast_accumulated_frame_num,
+            self.history_fix_frame_num,
+        );
+
+        c.min(
+            "diffuse_prepass_blur_radius",
+            self.diffuse_prepass_blur_radius,
+            0.0,
+        );
+        c.min(
+            "specular_prepass_blur_radius",
+            self.specular_prepass_blur_radius,
+            0.0,
+        );
+        c.min("diffuse_phi_luminance", self.diffuse_phi_luminance, 0.0);
+        c.min("specular_phi_luminance", self.specular_phi_luminance, 0.0);
+        c.range(
+            "diffuse_lobe_angle_fraction",
+            self.diffuse_lobe_angle_fraction,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "specular_lobe_angle_fraction",
+            self.specular_lobe_angle_fraction,
+            0.0,
+            1.0,
+        );
+        c.range("roughness_fraction", self.roughness_fraction, 0.0, 1.0);
+        c.min("specular_variance_boost", self.specular_variance_boost, 0.0);
+        c.min(
+            "specular_lobe_angle_slack",
+            self.specular_lobe_angle_slack,
+            0.0,
+        );
+        c.range(
+            "diffuse_min_luminance_weight",
+            self.diffuse_min_luminance_weight,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "specular_min_luminance_weight",
+            self.specular_min_luminance_weight,
+            0.0,
+            1.0,
+        );
+        c.min(
+            "luminance_edge_stopping_relaxation",
+            self.luminance_edge_stopping_relaxation,
+            0.0,
+        );
+        c.min(
+            "normal_edge_stopping_relaxation",
+            self.normal_edge_stopping_relaxation,
+            0.0,
+        );
+        c.min(
+            "roughness_edge_stopping_relaxation",
+            self.roughness_edge_stopping_relaxation,
+            0.0,
+        );
+    }
+}
+
+impl Check for RelaxDiffuseSettings {
+    fn check(&self, c: &mut Checker) {
+        relax_common!(self).check(c);
+        check_relax_history(
+            c,
+            "diffuse_",
+            self.diffuse_max_accumulated_frame_num,
+            self.diffuse_max_fast_accumulated_frame_num,
+            self.history_fix_frame_num,
+        );
+
+        c.min("prepass_blur_radius", self.prepass_blur_radius, 0.0);
+        c.min("diffuse_phi_luminance", self.diffuse_phi_luminance, 0.0);
+        c.range(
+            "diffuse_lobe_angle_fraction",
+            self.diffuse_lobe_angle_fraction,
+            0.0,
+            1.0,
+        );
+        c.range("min_luminance_weight", self.min_luminance_weight, 0.0, 1.0);
+    }
+}
+
+impl Check for RelaxSpecularSettings {
+    fn check(&self, c: &mut Checker) {
+        relax_common!(self).check(c);
+        check_relax_history(
+            c,
+            "specular_",
+            self.specular_max_accumulated_frame_num,
+            self.specular_max_fast_accumulated_frame_num,
+            self.history_fix_frame_num,
+        );
+
+        c.min("prepass_blur_radius", self.prepass_blur_radius, 0.0);
+        c.min("specular_phi_luminance", self.specular_phi_luminance, 0.0);
+        c.range(
+            "diffuse_lobe_angle_fraction",
+            self.diffuse_lobe_angle_fraction,
+            0.0,
+            1.0,
+        );
+        c.range(
+            "specular_lobe_angle_fraction",
+            self.specular_lobe_angle_fraction,
+            0.0,
+            1.0,
+        );
+        c.range("roughness_fraction", self.roughness_fraction, 0.0, 1.0);
+        c.min("specular_variance_boost", self.specular_variance_boost, 0.0);
+        c.min(
+            "specular_lobe_angle_slack",
+            self.specular_lobe_angle_slack,
+            0.0,
+        );
+        c.range("min_luminance_weight", self.min_luminance_weight, 0.0, 1.0);
+        c.min(
+            "luminance_edge_stopping_relaxation",
+            self.luminance_edge_stopping_relaxation,
+            0.0,
+        );
+        c.min(
+            "normal_edge_stopping_relaxation",
+            self.normal_edge_stopping_relaxation,
+            0.0,
+        );
+        c.min(
+            "roughness_edge_stopping_relaxation",
+            self.roughness_edge_stopping_relaxation,
+            0.0,
+        );
+    }
+}
+
+/// Error returned by the checked `Instance` setters.
+#[derive(Debug, Clone)]
+pub enum SettingsError {
+    // Settings were refused before reaching NRD
+    Invalid(Vec<Violation>),
+
+    // NRD rejected the settings
+    Nrd(ffi::Result),
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::Invalid(violations) => {
+                f.write_str("invalid settings")?;
+                for (i, violation) in violations.iter().enumerate() {
+                    f.write_str(if i == 0 { ": " } else { ", " })?;
+                    violation.fmt(f)?;
+                }
+                Ok(())
+            }
+            SettingsError::Nrd(result) => write!(f, "NRD returned {result:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+impl From<ffi::Result> for SettingsError {
+    fn from(result: ffi::Result) -> Self {
+        SettingsError::Nrd(result)
+    }
+}
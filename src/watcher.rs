@@ -0,0 +1,214 @@
+//! Hot reloading of denoiser settings from a TOML or RON file.
+//!
+//! The file maps identifiers to settings, fields that are left out keep their defaults:
+//! ```toml
+//! [[denoisers]]
+//! identifier = 0
+//!
+//! [denoisers.settings.reblur]
+//! max_accumulated_frame_num = 60
+//! enable_anti_firefly = true
+//! ```
+//! or in RON:
+//! ```ron
+//! (denoisers: [(identifier: 0, settings: reblur((max_accumulated_frame_num: 60)))])
+//! ```
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::ffi::{self, Denoiser, Identifier};
+use crate::{AnyDenoiserSettings, Instance, Violation};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettingsEntry {
+    pub identifier: Identifier,
+    pub settings: AnyDenoiserSettings,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SettingsFile {
+    #[serde(default)]
+    pub denoisers: Vec<SettingsEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsFormat {
+    Toml,
+    Ron,
+}
+
+impl SettingsFormat {
+    /// Picks the format from the file extension, `.toml` or `.ron`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(SettingsFormat::Toml),
+            "ron" => Some(SettingsFormat::Ron),
+            _ => None,
+        }
+    }
+
+    pub fn parse(self, source: &str) -> Result<SettingsFile, String> {
+        match self {
+            SettingsFormat::Toml => toml::from_str(source).map_err(|err| err.to_string()),
+            SettingsFormat::Ron => ron::from_str(source).map_err(|err| err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WatchError {
+    UnknownFormat(PathBuf),
+    Io(std::io::Error),
+    Parse(String),
+    Invalid(Vec<(Identifier, Vec<Violation>)>),
+    /// Entries whose identifier is unknown or whose settings are for another denoiser.
+    Mismatch(Vec<(Identifier, String)>),
+    Nrd(ffi::Result),
+}
+
+impl Display for WatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatchError::UnknownFormat(path) => {
+                write!(f, "{}: expected a .toml or .ron file", path.display())
+            }
+            WatchError::Io(err) => err.fmt(f),
+            WatchError::Parse(err) => f.write_str(err),
+            WatchError::Invalid(denoisers) => {
+                for (identifier, violations) in denoisers {
+                    for violation in violations {
+                        writeln!(f, "denoiser {}: {}", identifier.0, violation)?;
+                    }
+                }
+                Ok(())
+            }
+            WatchError::Mismatch(denoisers) => {
+                for (identifier, mismatch) in denoisers {
+                    writeln!(f, "denoiser {}: {}", identifier.0, mismatch)?;
+                }
+                Ok(())
+            }
+            WatchError::Nrd(err) => write!(f, "NRD returned {err:?}"),
+        }
+    }
+}
+
+impl std::error::Error for WatchError {}
+
+impl From<std::io::Error> for WatchError {
+    fn from(err: std::io::Error) -> Self {
+        WatchError::Io(err)
+    }
+}
+
+/// Polls a settings file and queues its contents whenever it changes.
+///
+/// Call [`SettingsWatcher::poll`] once per frame and [`SettingsWatcher::apply`] before
+/// `get_compute_dispatches`. A file that fails to parse or validate is reported once and the
+/// previously applied settings stay in effect until the file is fixed.
+pub struct SettingsWatcher {
+    path: PathBuf,
+    format: SettingsFormat,
+    // Modification time and length of the last file read
+    stamp: Option<(SystemTime, u64)>,
+    pending: Vec<SettingsEntry>,
+}
+
+impl SettingsWatcher {
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, WatchError> {
+        let path = path.into();
+        let format = SettingsFormat::from_path(&path)
+            .ok_or_else(|| WatchError::UnknownFormat(path.clone()))?;
+        Ok(Self::with_format(path, format))
+    }
+
+    pub fn with_format(path: impl Into<PathBuf>, format: SettingsFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            stamp: None,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Re-reads the file if it changed since the last poll. Returns `Ok(true)` if new settings
+    /// were queued.
+    pub fn poll(&mut self) -> Result<bool, WatchError> {
+        let metadata = std::fs::metadata(&self.path)?;
+        let stamp = (metadata.modified()?, metadata.len());
+        if self.stamp == Some(stamp) {
+            return Ok(false);
+        }
+        self.stamp = Some(stamp);
+
+        let source = std::fs::read_to_string(&self.path)?;
+        let file = self.format.parse(&source).map_err(WatchError::Parse)?;
+        let invalid: Vec<_> = file
+            .denoisers
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .settings
+                    .validate()
+                    .err()
+                    .map(|violations| (entry.identifier, violations))
+            })
+            .collect();
+        if !invalid.is_empty() {
+            return Err(WatchError::Invalid(invalid));
+        }
+
+        self.pending = file.denoisers;
+        Ok(true)
+    }
+
+    /// Settings read by the last successful [`SettingsWatcher::poll`] that weren't applied yet.
+    pub fn pending(&self) -> &[SettingsEntry] {
+        &self.pending
+    }
+
+    /// Forwards pending settings to the instance. Entries whose settings don't match the
+    /// denoiser of their identifier are dropped and reported as [`WatchError::Mismatch`] before
+    /// anything is forwarded, the other entries stay pending for the next call. Entries NRD
+    /// rejects stay pending as well.
+    pub fn apply(&mut self, instance: &mut Instance) -> Result<(), WatchError> {
+        let mut mismatches = Vec::new();
+        self.pending.retain(|entry| {
+            let denoiser = instance.denoiser(entry.identifier);
+            let matches = denoiser.and_then(Denoiser::settings_kind) == Some(entry.settings.kind());
+            if !matches {
+                let mismatch = match denoiser {
+                    Some(denoiser) => format!(
+                        "{:?} settings don't apply to {:?}",
+                        entry.settings.kind(),
+                        denoiser
+                    ),
+                    None => "unknown identifier".to_string(),
+                };
+                mismatches.push((entry.identifier, mismatch));
+            }
+            matches
+        });
+        if !mismatches.is_empty() {
+            return Err(WatchError::Mismatch(mismatches));
+        }
+
+        let mut result = Ok(());
+        self.pending.retain(
+            |entry| match entry.settings.apply(instance, entry.identifier) {
+                Ok(()) => false,
+                Err(err) => {
+                    result = Err(WatchError::Nrd(err));
+                    true
+                }
+            },
+        );
+        result
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "capture")]
+
+use nrd_sys::capture::{replay, Call, Capture, Recorder};
+use nrd_sys::{CommonSettings, Denoiser, DenoiserDesc, Identifier, ReblurSettings, SigmaSettings};
+
+fn record() -> Capture {
+    let mut recorder = Recorder::new(&[DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    }])
+    .unwrap();
+    recorder
+        .set_denoiser_settings(Identifier(0), &ReblurSettings::default())
+        .unwrap();
+    for frame_index in 0..3 {
+        recorder
+            .set_common_settings(&CommonSettings {
+                frame_index,
+                ..Default::default()
+            })
+            .unwrap();
+        recorder.get_compute_dispatches(&[Identifier(0)]).unwrap();
+    }
+    // Failures are part of the capture too
+    assert!(recorder.get_compute_dispatches(&[Identifier(7)]).is_err());
+    recorder.into_capture()
+}
+
+#[test]
+fn capture_round_trips_and_replays() {
+    let capture = record();
+    assert_eq!(capture.calls.len(), 9);
+
+    let mut bytes = Vec::new();
+    capture.save(&mut bytes).unwrap();
+    let loaded = Capture::load(bytes.as_slice()).unwrap();
+    assert_eq!(loaded, capture);
+    assert!(Capture::load(&b"not a capture"[..]).is_err());
+
+    assert_eq!(replay(&loaded), []);
+}
+
+#[test]
+fn replay_reports_differences() {
+    let mut capture = record();
+    let Call::GetComputeDispatches {
+        dispatches: Ok(dispatches),
+        ..
+    } = &mut capture.calls[3]
+    else {
+        panic!("expected a dispatch query");
+    };
+    dispatches[0].constant_buffer[0] ^= 1;
+
+    let mismatches = replay(&capture);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].call, 3);
+    assert!(mismatches[0].description.contains("constant buffer"));
+}
+
+#[test]
+fn replay_refuses_mismatched_settings() {
+    let mut capture = record();
+    let Call::SetDenoiserSettings { settings, .. } = &mut capture.calls[1] else {
+        panic!("expected a settings upload");
+    };
+    *settings = SigmaSettings::default().into();
+
+    let mismatches = replay(&capture);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].call, 1);
+    assert!(mismatches[0].description.contains("ReblurDiffuse"));
+}
+
+#[test]
+fn failed_creation_is_captured() {
+    let desc = DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    };
+    // Identifiers must be unique
+    let Err((_, capture)) = Recorder::new(&[desc.clone(), desc]) else {
+        panic!("expected the creation to fail");
+    };
+    assert_eq!(capture.calls.len(), 1);
+    assert!(matches!(
+        capture.calls[0],
+        Call::CreateInstance { desc: Err(_), .. }
+    ));
+    assert_eq!(replay(&capture), []);
+}
@@ -0,0 +1,49 @@
+use nrd_sys::{Format, NumericType};
+
+#[test]
+fn format_metadata() {
+    assert_eq!(Format::RGBA16_SFLOAT.bytes_per_pixel(), 8);
+    assert_eq!(Format::RGB32_SFLOAT.bytes_per_pixel(), 12);
+    assert_eq!(Format::R11_G11_B10_UFLOAT.bytes_per_pixel(), 4);
+    assert_eq!(Format::R11_G11_B10_UFLOAT.channel_count(), 3);
+    assert_eq!(Format::R10_G10_B10_A2_UNORM.channel_count(), 4);
+    assert_eq!(Format::RG8_SNORM.numeric_type(), NumericType::Snorm);
+    assert_eq!(Format::RGBA8_SRGB.numeric_type(), NumericType::Unorm);
+    assert!(Format::RGBA8_SRGB.is_srgb());
+    assert!(!Format::RGBA8_UNORM.is_srgb());
+
+    assert_eq!(Format::RGBA16_SFLOAT.to_dxgi(), 10);
+    assert_eq!(Format::R9_G9_B9_E5_UFLOAT.to_dxgi(), 67);
+}
+
+#[test]
+fn storage_support_explains_failures() {
+    assert!(Format::RGBA16_SFLOAT.storage_support().is_ok());
+    let err = Format::R9_G9_B9_E5_UFLOAT.storage_support().unwrap_err();
+    assert_eq!(err.format, Format::R9_G9_B9_E5_UFLOAT);
+    assert!(err.to_string().contains("R11_G11_B10_UFLOAT"));
+}
+
+#[cfg(feature = "ash")]
+#[test]
+fn vulkan_formats() {
+    use ash::vk;
+    // `vk::Format` only implements `Debug` with ash's "debug" feature
+    assert!(Format::RG16_SFLOAT.to_vk() == vk::Format::R16G16_SFLOAT);
+    assert!(Format::R10_G10_B10_A2_UNORM.to_vk() == vk::Format::A2B10G10R10_UNORM_PACK32);
+}
+
+#[cfg(feature = "wgpu-types")]
+#[test]
+fn wgpu_formats() {
+    use wgpu_types::{Features, TextureFormat};
+    assert_eq!(
+        Format::R11_G11_B10_UFLOAT.to_wgpu(),
+        Ok(TextureFormat::Rg11b10Ufloat)
+    );
+    assert!(Format::RGB32_UINT.to_wgpu().is_err());
+    assert_eq!(
+        Format::R16_UNORM.wgpu_features(),
+        Features::TEXTURE_FORMAT_16BIT_NORM
+    );
+}
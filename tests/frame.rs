@@ -0,0 +1,97 @@
+use std::time::{Duration, Instant};
+
+use nrd_sys::{AccumulationMode, Camera, ColumnMajor, CommonSettings, FrameState};
+
+// A camera that differs from the others in every tracked field
+fn camera(i: u8) -> Camera {
+    let i = i as f32;
+    let mut world_to_view = [0.0; 16];
+    world_to_view[0] = 1.0;
+    world_to_view[5] = 1.0;
+    world_to_view[10] = 1.0;
+    world_to_view[12] = i;
+    world_to_view[15] = 1.0;
+    Camera::new(ColumnMajor([i + 1.0; 16]), ColumnMajor(world_to_view))
+        .with_jitter([0.1 * i, -0.1 * i])
+        .with_resolution_scale([1.0 - 0.1 * i, 1.0 - 0.05 * i])
+}
+
+fn assert_prev_is_current(settings: &CommonSettings) {
+    assert_eq!(
+        settings.view_to_clip_matrix_prev,
+        settings.view_to_clip_matrix
+    );
+    assert_eq!(
+        settings.world_to_view_matrix_prev,
+        settings.world_to_view_matrix
+    );
+    assert_eq!(settings.camera_jitter_prev, settings.camera_jitter);
+    assert_eq!(settings.resolution_scale_prev, settings.resolution_scale);
+}
+
+#[test]
+fn first_frame_clears_history() {
+    let mut frame = FrameState::new(CommonSettings::default());
+    let settings = frame.advance(&camera(1), Instant::now());
+    assert_prev_is_current(settings);
+    assert_eq!(settings.world_to_view_matrix[12], 1.0);
+    assert_eq!(settings.frame_index, 0);
+    assert_eq!(
+        settings.accumulation_mode,
+        AccumulationMode::ClearAndRestart
+    );
+}
+
+#[test]
+fn camera_rotates_into_prev() {
+    let start = Instant::now();
+    let mut frame = FrameState::new(CommonSettings::default());
+    frame.advance(&camera(1), start);
+    let first = frame.settings().clone();
+
+    let second = frame.advance(&camera(2), start + Duration::from_millis(20));
+    assert_eq!(second.view_to_clip_matrix_prev, first.view_to_clip_matrix);
+    assert_eq!(second.world_to_view_matrix_prev, first.world_to_view_matrix);
+    assert_eq!(second.camera_jitter_prev, first.camera_jitter);
+    assert_eq!(second.resolution_scale_prev, first.resolution_scale);
+    assert_eq!(second.view_to_clip_matrix, camera(2).view_to_clip);
+    assert_eq!(second.world_to_view_matrix, camera(2).world_to_view);
+    assert_eq!(second.camera_jitter, camera(2).jitter);
+    assert_eq!(second.resolution_scale, camera(2).resolution_scale);
+    assert_eq!(second.frame_index, 1);
+    assert_eq!(second.accumulation_mode, AccumulationMode::Continue);
+    assert!((second.time_delta_between_frames - 20.0).abs() < 1e-3);
+
+    let third = frame.advance(&camera(3), start + Duration::from_millis(40));
+    assert_eq!(third.world_to_view_matrix_prev, camera(2).world_to_view);
+    assert_eq!(third.frame_index, 2);
+}
+
+#[test]
+fn restart_uses_current_camera_as_prev() {
+    let start = Instant::now();
+    let mut frame = FrameState::new(CommonSettings::default());
+    frame.advance(&camera(1), start);
+    frame.advance(&camera(2), start);
+
+    frame.restart();
+    let settings = frame.advance(&camera(3), start);
+    assert_prev_is_current(settings);
+    assert_eq!(settings.world_to_view_matrix[12], 3.0);
+    assert_eq!(settings.accumulation_mode, AccumulationMode::Restart);
+    // A restart doesn't reset the frame index
+    assert_eq!(settings.frame_index, 2);
+
+    frame.clear_and_restart();
+    frame.restart();
+    let settings = frame.advance(&camera(4), start);
+    assert_prev_is_current(settings);
+    assert_eq!(
+        settings.accumulation_mode,
+        AccumulationMode::ClearAndRestart
+    );
+
+    let settings = frame.advance(&camera(5), start);
+    assert_eq!(settings.accumulation_mode, AccumulationMode::Continue);
+    assert_eq!(settings.world_to_view_matrix_prev, camera(4).world_to_view);
+}
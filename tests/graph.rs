@@ -0,0 +1,40 @@
+use nrd_sys::{Denoiser, DenoiserDesc, DispatchGraph, Identifier, Instance, ResourceType};
+
+#[test]
+fn dispatch_graph_export() {
+    let mut instance = Instance::new(&[DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    }])
+    .unwrap();
+    let graph = DispatchGraph::from_instance(&mut instance, &[Identifier(0)]).unwrap();
+    let dispatches = instance.get_compute_dispatches(&[Identifier(0)]).unwrap();
+
+    assert_eq!(graph.dispatches.len(), dispatches.len());
+    let accesses: usize = dispatches.iter().map(|d| d.resources().len()).sum();
+    assert_eq!(graph.accesses.len(), accesses);
+    for access in &graph.accesses {
+        assert!(access.dispatch < graph.dispatches.len());
+        assert!(access.resource < graph.resources.len());
+    }
+    for resource in &graph.resources {
+        let pooled = matches!(
+            resource.ty,
+            ResourceType::PERMANENT_POOL | ResourceType::TRANSIENT_POOL
+        );
+        assert_eq!(resource.texture.is_some(), pooled);
+    }
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph nrd {"));
+    assert!(dot.contains(&*dispatches[0].name().to_string_lossy()));
+
+    let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+    assert_eq!(
+        json["dispatches"].as_array().unwrap().len(),
+        graph.dispatches.len()
+    );
+    assert_eq!(json["accesses"].as_array().unwrap().len(), accesses);
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "egui")]
+
+use nrd_sys::{
+    CommonSettings, ReblurSettings, ReferenceSettings, RelaxDiffuseSettings,
+    RelaxDiffuseSpecularSettings, RelaxSpecularSettings, SigmaSettings,
+    REBLUR_MAX_HISTORY_FRAME_NUM,
+};
+
+fn show(mut f: impl FnMut(&mut egui::Ui) -> bool) -> bool {
+    let ctx = egui::Context::default();
+    let mut changed = false;
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            changed = f(ui);
+        });
+    });
+    changed
+}
+
+// Runs one frame with the given pointer events and returns what the inspector reported
+fn frame(
+    ctx: &egui::Context,
+    events: Vec<egui::Event>,
+    mut f: impl FnMut(&mut egui::Ui) -> bool,
+) -> bool {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut changed = false;
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            changed = f(ui);
+        });
+    });
+    changed
+}
+
+// The rect of the first widget laid out last frame that only senses clicks or only drags
+fn first_widget(ctx: &egui::Context, click: bool) -> egui::Rect {
+    ctx.viewport(|viewport| {
+        viewport
+            .prev_pass
+            .widgets
+            .layers()
+            .flat_map(|(_, widgets)| widgets)
+            .find(|w| w.sense.senses_click() == click && w.sense.senses_drag() != click)
+            .expect("no matching widget")
+            .rect
+    })
+}
+
+fn pointer_button(pos: egui::Pos2, pressed: bool) -> egui::Event {
+    egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    }
+}
+
+fn click(ctx: &egui::Context, f: impl FnMut(&mut egui::Ui) -> bool) -> bool {
+    let pos = first_widget(ctx, true).center();
+    frame(
+        ctx,
+        vec![
+            egui::Event::PointerMoved(pos),
+            pointer_button(pos, true),
+            pointer_button(pos, false),
+        ],
+        f,
+    )
+}
+
+#[test]
+fn inspectors_report_no_change_without_input() {
+    let mut common = CommonSettings {
+        denoising_range: 1e9,
+        ..Default::default()
+    };
+    assert!(!show(|ui| common.ui(ui)));
+    // Out-of-range values are left for validation to report, not silently clamped
+    assert_eq!(common.denoising_range, 1e9);
+
+    let mut reblur = ReblurSettings::default();
+    assert!(!show(|ui| reblur.ui(ui)));
+    let mut relax = RelaxDiffuseSpecularSettings::default();
+    assert!(!show(|ui| relax.ui(ui)));
+    let mut relax_diffuse = RelaxDiffuseSettings::default();
+    assert!(!show(|ui| relax_diffuse.ui(ui)));
+    let mut relax_specular = RelaxSpecularSettings::default();
+    assert!(!show(|ui| relax_specular.ui(ui)));
+    let mut sigma = SigmaSettings::default();
+    assert!(!show(|ui| sigma.ui(ui)));
+    let mut reference = ReferenceSettings::default();
+    assert!(!show(|ui| reference.ui(ui)));
+}
+
+#[test]
+fn reset_restores_defaults() {
+    let ctx = egui::Context::default();
+    let mut reblur = ReblurSettings {
+        blur_radius: 5.0,
+        max_accumulated_frame_num: 3,
+        ..Default::default()
+    };
+    assert!(!frame(&ctx, Vec::new(), |ui| reblur.ui(ui)));
+    assert!(click(&ctx, |ui| reblur.ui(ui)));
+    assert_eq!(reblur, ReblurSettings::default());
+}
+
+#[test]
+fn reset_keeps_the_camera() {
+    let ctx = egui::Context::default();
+    let mut common = CommonSettings {
+        denoising_range: 10.0,
+        camera_jitter: [0.25, -0.25],
+        resolution_scale: [0.5, 0.5],
+        frame_index: 7,
+        ..Default::default()
+    };
+    common.world_to_view_matrix[12] = 3.0;
+    common.world_prev_to_world_matrix[13] = 2.0;
+    let camera = common.clone();
+
+    assert!(!frame(&ctx, Vec::new(), |ui| common.ui(ui)));
+    assert!(click(&ctx, |ui| common.ui(ui)));
+    assert_eq!(
+        common.denoising_range,
+        CommonSettings::default().denoising_range
+    );
+    assert_eq!(common.world_to_view_matrix, camera.world_to_view_matrix);
+    assert_eq!(
+        common.world_prev_to_world_matrix,
+        camera.world_prev_to_world_matrix
+    );
+    assert_eq!(common.camera_jitter, camera.camera_jitter);
+    assert_eq!(common.resolution_scale, camera.resolution_scale);
+    assert_eq!(common.frame_index, camera.frame_index);
+}
+
+#[test]
+fn dragging_a_slider_changes_its_value() {
+    let ctx = egui::Context::default();
+    let mut reblur = ReblurSettings::default();
+    assert!(!frame(&ctx, Vec::new(), |ui| reblur.ui(ui)));
+
+    // The sections are collapsed, so the first slider is `max_accumulated_frame_num`
+    let rail = first_widget(&ctx, false);
+    let (start, end) = (rail.left_center(), rail.right_center());
+    assert!(frame(
+        &ctx,
+        vec![
+            egui::Event::PointerMoved(start),
+            pointer_button(start, true)
+        ],
+        |ui| reblur.ui(ui)
+    ));
+    assert_eq!(reblur.max_accumulated_frame_num, 0);
+    assert!(frame(&ctx, vec![egui::Event::PointerMoved(end)], |ui| {
+        reblur.ui(ui)
+    }));
+    assert_eq!(
+        reblur.max_accumulated_frame_num,
+        REBLUR_MAX_HISTORY_FRAME_NUM
+    );
+    frame(&ctx, vec![pointer_button(end, false)], |ui| reblur.ui(ui));
+
+    // Nothing else was touched
+    reblur.max_accumulated_frame_num = ReblurSettings::default().max_accumulated_frame_num;
+    assert_eq!(reblur, ReblurSettings::default());
+}
@@ -0,0 +1,297 @@
+use nrd_sys::{
+    family, AccumulationMode, Camera, ColumnMajor, CommonSettings, Denoiser, DenoiserDesc,
+    Identifier, Instance, InstanceBuilder, ManagedInstance, MultiViewDenoiser, ReblurSettings,
+    SettingsChanges, SigmaSettings,
+};
+
+fn reblur_instance() -> Instance {
+    Instance::new(&[DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    }])
+    .unwrap()
+}
+
+#[test]
+fn unchanged_settings_are_skipped() {
+    let mut instance = reblur_instance();
+    assert!(instance.take_changes().is_empty());
+
+    let mut settings = ReblurSettings::default();
+    instance
+        .set_denoiser_settings(Identifier(0), &settings)
+        .unwrap();
+    assert_eq!(instance.take_changes().denoisers, [Identifier(0)]);
+
+    instance
+        .set_denoiser_settings(Identifier(0), &settings)
+        .unwrap();
+    assert!(instance.take_changes().is_empty());
+
+    settings.blur_radius = 20.0;
+    instance
+        .set_denoiser_settings(Identifier(0), &settings)
+        .unwrap();
+    assert_eq!(instance.take_changes().denoisers, [Identifier(0)]);
+    assert_eq!(
+        instance.denoiser_settings(Identifier(0)),
+        Some(&settings.into())
+    );
+}
+
+#[test]
+fn per_frame_common_settings_are_not_changes() {
+    let mut instance = reblur_instance();
+    let mut common = CommonSettings::default();
+    instance.set_common_settings(&common).unwrap();
+    assert_eq!(
+        instance.take_changes(),
+        SettingsChanges {
+            common: true,
+            denoisers: Vec::new()
+        }
+    );
+
+    common.frame_index += 1;
+    common.camera_jitter = [0.25, -0.25];
+    common.world_prev_to_world_matrix[12] = 2.0;
+    instance.set_common_settings(&common).unwrap();
+    assert!(instance.take_changes().is_empty());
+    assert_eq!(instance.common_settings().unwrap().frame_index, 1);
+
+    common.denoising_range = 1000.0;
+    instance.set_common_settings(&common).unwrap();
+    assert!(instance.take_changes().common);
+}
+
+#[test]
+fn builder_allocates_unique_identifiers() {
+    let mut builder = InstanceBuilder::new();
+    let reblur = builder.add::<family::Reblur>(Denoiser::ReblurDiffuseSpecular, 64, 32);
+    let sigma = builder.add::<family::Sigma>(Denoiser::SigmaShadow, 64, 32);
+    assert_ne!(reblur.identifier(), sigma.identifier());
+    assert_eq!(builder.denoisers().len(), 2);
+
+    let mut instance = builder.build().unwrap();
+    instance
+        .set_settings(reblur, &ReblurSettings::default())
+        .unwrap();
+    instance
+        .set_settings(sigma, &SigmaSettings::default())
+        .unwrap();
+    assert_eq!(
+        instance.take_changes().denoisers,
+        [reblur.identifier(), sigma.identifier()]
+    );
+    assert!(!instance.get_dispatches(sigma).unwrap().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn builder_rejects_denoiser_from_other_family() {
+    InstanceBuilder::new().add::<family::Sigma>(Denoiser::ReblurDiffuse, 64, 64);
+}
+
+#[test]
+fn handles_are_rejected_by_other_instances() {
+    let mut builder = InstanceBuilder::new();
+    let reblur = builder.add::<family::Reblur>(Denoiser::ReblurDiffuse, 64, 64);
+    let mut other = InstanceBuilder::new();
+    other.add::<family::Sigma>(Denoiser::SigmaShadow, 64, 64);
+
+    for mut instance in [
+        other.build().unwrap(),
+        Instance::new(builder.denoisers()).unwrap(),
+        builder.clone().build().unwrap(),
+    ] {
+        let err = instance
+            .set_settings(reblur, &ReblurSettings::default())
+            .unwrap_err();
+        assert_eq!(format!("{err:?}"), "InvalidArgument");
+        let err = instance.get_dispatches(reblur).unwrap_err();
+        assert_eq!(format!("{err:?}"), "InvalidArgument");
+    }
+
+    let mut managed = ManagedInstance::from_builder(&builder).unwrap();
+    assert!(managed.resize(128, 72).unwrap());
+    managed
+        .set_settings(reblur, &ReblurSettings::default())
+        .unwrap();
+}
+
+#[test]
+fn managed_instance_recreates_on_resize() {
+    let mut builder = InstanceBuilder::new();
+    let reblur = builder.add::<family::Reblur>(Denoiser::ReblurDiffuse, 64, 64);
+    let mut managed = ManagedInstance::from_builder(&builder).unwrap();
+
+    let settings = ReblurSettings {
+        blur_radius: 25.0,
+        ..Default::default()
+    };
+    managed.set_settings(reblur, &settings).unwrap();
+    managed
+        .set_common_settings(&CommonSettings::default())
+        .unwrap();
+    managed.take_changes();
+
+    assert!(!managed.resize(64, 64).unwrap());
+    assert!(managed.resize(128, 72).unwrap());
+    assert_eq!(managed.denoisers()[0].render_width, 128);
+    assert_eq!(managed.denoisers()[0].render_height, 72);
+
+    // Denoiser settings survive the recreation, common settings wait for the next frame
+    assert_eq!(
+        managed.instance().denoiser_settings(reblur.identifier()),
+        Some(&settings.into())
+    );
+    assert!(managed.instance().common_settings().is_none());
+    managed
+        .set_common_settings(&CommonSettings::default())
+        .unwrap();
+    assert_eq!(
+        managed
+            .instance()
+            .common_settings()
+            .unwrap()
+            .accumulation_mode,
+        AccumulationMode::ClearAndRestart
+    );
+    managed
+        .set_common_settings(&CommonSettings::default())
+        .unwrap();
+    assert_eq!(
+        managed
+            .instance()
+            .common_settings()
+            .unwrap()
+            .accumulation_mode,
+        AccumulationMode::Continue
+    );
+    assert!(managed.take_changes().denoisers.is_empty());
+}
+
+#[test]
+fn multi_view_dispatches_are_interleaved() {
+    let denoisers = [DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    }];
+    let mut denoiser = MultiViewDenoiser::new(&denoisers, 2, CommonSettings::default()).unwrap();
+    assert_eq!(denoiser.view_count(), 2);
+    assert!(!denoiser.pipelines().is_empty());
+
+    let cameras = [
+        Camera::new(ColumnMajor([1.0; 16]), ColumnMajor([1.0; 16])),
+        Camera::new(ColumnMajor([2.0; 16]), ColumnMajor([2.0; 16])),
+    ];
+    let now = std::time::Instant::now();
+    denoiser.advance(&cameras, now).unwrap();
+    denoiser.frame_state_mut(1).restart();
+    denoiser.advance(&cameras, now).unwrap();
+
+    // Each view keeps its own history
+    assert_eq!(
+        denoiser
+            .instance(0)
+            .common_settings()
+            .unwrap()
+            .accumulation_mode,
+        AccumulationMode::Continue
+    );
+    assert_eq!(
+        denoiser
+            .instance(1)
+            .common_settings()
+            .unwrap()
+            .accumulation_mode,
+        AccumulationMode::Restart
+    );
+    assert_eq!(
+        denoiser.frame_state(1).settings().world_to_view_matrix,
+        [2.0; 16]
+    );
+
+    let dispatches = denoiser.get_compute_dispatches(&[Identifier(0)]).unwrap();
+    let views: Vec<_> = dispatches.iter().map(|d| d.view).collect();
+    assert_eq!(views.len() % 2, 0);
+    for (i, dispatch) in dispatches.iter().enumerate() {
+        assert_eq!(dispatch.view, i % 2);
+    }
+    for pair in dispatches.chunks(2) {
+        assert_eq!(
+            pair[0].dispatch.pipeline_index,
+            pair[1].dispatch.pipeline_index
+        );
+    }
+}
+
+#[test]
+fn settings_of_another_family_are_rejected() {
+    let mut instance = Instance::new(&[DenoiserDesc {
+        identifier: Identifier(0),
+        denoiser: Denoiser::ReblurDiffuse,
+        render_width: 64,
+        render_height: 64,
+    }])
+    .unwrap();
+    let err = instance
+        .set_denoiser_settings(Identifier(0), &SigmaSettings::default())
+        .unwrap_err();
+    assert_eq!(format!("{err:?}"), "InvalidArgument");
+    let err = instance
+        .set_denoiser_settings(Identifier(1), &ReblurSettings::default())
+        .unwrap_err();
+    assert_eq!(format!("{err:?}"), "InvalidArgument");
+    assert_eq!(instance.denoiser_settings(Identifier(0)), None);
+    instance
+        .set_denoiser_settings(Identifier(0), &ReblurSettings::default())
+        .unwrap();
+}
+
+#[cfg(feature = "hot-reload")]
+#[test]
+fn watcher_drops_mismatched_settings() {
+    use nrd_sys::watcher::{SettingsWatcher, WatchError};
+
+    let path = std::env::temp_dir().join(format!("nrd-sys-{}-mismatch.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        "[[denoisers]]\nidentifier = 0\n\n[denoisers.settings.sigma]\n\n\
+         [[denoisers]]\nidentifier = 5\n\n[denoisers.settings.sigma]\n\n\
+         [[denoisers]]\nidentifier = 0\n\n[denoisers.settings.reblur]\nblur_radius = 20.0\n",
+    )
+    .unwrap();
+    let mut instance = reblur_instance();
+
+    let mut watcher = SettingsWatcher::new(&path).unwrap();
+    assert!(watcher.poll().unwrap());
+    match watcher.apply(&mut instance) {
+        Err(WatchError::Mismatch(mismatches)) => {
+            assert_eq!(mismatches.len(), 2);
+            assert_eq!(mismatches[0].0, Identifier(0));
+            assert!(mismatches[0].1.contains("ReblurDiffuse"));
+            assert_eq!(mismatches[1], (Identifier(5), "unknown identifier".into()));
+        }
+        other => panic!("expected a mismatch, got {other:?}"),
+    }
+    // Nothing is forwarded alongside a mismatch, the matching entry is applied next time
+    assert_eq!(watcher.pending().len(), 1);
+    assert_eq!(instance.denoiser_settings(Identifier(0)), None);
+    watcher.apply(&mut instance).unwrap();
+    assert!(watcher.pending().is_empty());
+    let settings = ReblurSettings {
+        blur_radius: 20.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        instance.denoiser_settings(Identifier(0)),
+        Some(&settings.into())
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,107 @@
+use nrd_sys::{Jitter, JitterPattern, JitterSequence};
+
+const PATTERNS: [JitterPattern; 2] = [JitterPattern::Halton23, JitterPattern::R2];
+
+fn samples(pattern: JitterPattern, count: u32) -> Vec<[f32; 2]> {
+    let sequence = JitterSequence::new(pattern, count);
+    (0..count).map(|i| sequence.sample(i)).collect()
+}
+
+#[test]
+fn samples_stay_within_a_pixel() {
+    for pattern in PATTERNS {
+        for [x, y] in samples(pattern, 256) {
+            assert!((-0.5..=0.5).contains(&x), "{pattern:?}: x = {x}");
+            assert!((-0.5..=0.5).contains(&y), "{pattern:?}: y = {y}");
+        }
+    }
+}
+
+#[test]
+fn halton_starts_with_known_values() {
+    let expected = [
+        [0.0, -1.0 / 6.0],
+        [-0.25, 1.0 / 6.0],
+        [0.25, -7.0 / 18.0],
+        [-0.375, -1.0 / 18.0],
+    ];
+    for (sample, expected) in samples(JitterPattern::Halton23, 4).iter().zip(expected) {
+        assert!((sample[0] - expected[0]).abs() < 1e-6);
+        assert!((sample[1] - expected[1]).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn samples_are_centered() {
+    for pattern in PATTERNS {
+        let samples = samples(pattern, 64);
+        let mean_x = samples.iter().map(|s| s[0]).sum::<f32>() / 64.0;
+        let mean_y = samples.iter().map(|s| s[1]).sum::<f32>() / 64.0;
+        assert!(mean_x.abs() < 0.02, "{pattern:?}: mean x = {mean_x}");
+        assert!(mean_y.abs() < 0.02, "{pattern:?}: mean y = {mean_y}");
+    }
+}
+
+#[test]
+fn samples_cover_the_pixel_evenly() {
+    for pattern in PATTERNS {
+        let mut cells = [0u32; 16];
+        for [x, y] in samples(pattern, 64) {
+            let cx = (((x + 0.5) * 4.0) as usize).min(3);
+            let cy = (((y + 0.5) * 4.0) as usize).min(3);
+            cells[cy * 4 + cx] += 1;
+        }
+        for (cell, count) in cells.iter().enumerate() {
+            assert!(
+                (2..=6).contains(count),
+                "{pattern:?}: cell {cell} has {count} of 64 samples"
+            );
+        }
+    }
+}
+
+#[test]
+fn sequence_wraps_after_phase_count() {
+    for pattern in PATTERNS {
+        let mut sequence = JitterSequence::new(pattern, 8);
+        let first: Vec<_> = (0..8).map(|_| sequence.advance(64, 64)).collect();
+        let second: Vec<_> = (0..8).map(|_| sequence.advance(64, 64)).collect();
+        assert_eq!(first, second);
+    }
+}
+
+#[test]
+fn phase_count_scales_with_resolution() {
+    let native = JitterSequence::with_resolution_scale(JitterPattern::Halton23, 8, [1.0, 1.0]);
+    assert_eq!(native.phase_count(), 8);
+    let half = JitterSequence::with_resolution_scale(JitterPattern::Halton23, 8, [0.5, 0.5]);
+    assert_eq!(half.phase_count(), 32);
+    let third = JitterSequence::with_resolution_scale(JitterPattern::R2, 8, [0.67, 0.67]);
+    assert_eq!(third.phase_count(), 18);
+}
+
+#[test]
+fn tiny_resolution_scale_is_clamped() {
+    let tiny = JitterSequence::with_resolution_scale(JitterPattern::Halton23, 8, [1e-20, 1e-20]);
+    assert_eq!(tiny.phase_count(), 64);
+}
+
+#[test]
+#[should_panic]
+fn zero_resolution_scale_is_rejected() {
+    JitterSequence::with_resolution_scale(JitterPattern::R2, 8, [0.0, 1.0]);
+}
+
+#[test]
+#[should_panic]
+fn non_finite_resolution_scale_is_rejected() {
+    JitterSequence::with_resolution_scale(JitterPattern::R2, 8, [f32::NAN, 1.0]);
+}
+
+#[test]
+fn projection_offset_is_in_ndc() {
+    let jitter = Jitter::new([0.25, -0.5], 100, 200);
+    assert_eq!(jitter.camera_jitter, [0.25, -0.5]);
+    assert!((jitter.projection_offset[0] - 0.005).abs() < 1e-7);
+    assert!((jitter.projection_offset[1] + 0.005).abs() < 1e-7);
+}
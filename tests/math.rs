@@ -0,0 +1,79 @@
+use nrd_sys::{ColumnMajor, CommonSettings, IntoMatrix4, RowMajor};
+
+const TRANSLATION: [f32; 3] = [1.0, -2.0, 3.5];
+
+// A translation by `TRANSLATION` with a non-uniform scale, so that swapped rows and columns show
+#[rustfmt::skip]
+const COLUMN_MAJOR: [f32; 16] = [
+    2.0, 0.0, 0.0, 0.0,
+    0.0, 3.0, 0.0, 0.0,
+    0.0, 0.0, 4.0, 0.0,
+    1.0, -2.0, 3.5, 1.0,
+];
+
+#[rustfmt::skip]
+const ROW_MAJOR: [f32; 16] = [
+    2.0, 0.0, 0.0, 1.0,
+    0.0, 3.0, 0.0, -2.0,
+    0.0, 0.0, 4.0, 3.5,
+    0.0, 0.0, 0.0, 1.0,
+];
+
+fn assert_translation(m: [f32; 16]) {
+    assert_eq!(m[12..15], TRANSLATION);
+    assert_eq!(m, COLUMN_MAJOR);
+}
+
+#[test]
+fn column_major_is_unchanged() {
+    assert_translation(ColumnMajor(COLUMN_MAJOR).into_column_major());
+}
+
+#[test]
+fn row_major_is_transposed() {
+    assert_translation(RowMajor(ROW_MAJOR).into_column_major());
+
+    // Transposing back gives the input
+    let m = RowMajor(ROW_MAJOR).into_column_major();
+    assert_eq!(RowMajor(m).into_column_major(), ROW_MAJOR);
+}
+
+#[test]
+fn builders_convert_matrices() {
+    let settings = CommonSettings::default()
+        .with_world_to_view(RowMajor(ROW_MAJOR))
+        .with_view_to_clip(ColumnMajor(COLUMN_MAJOR));
+    assert_translation(settings.world_to_view_matrix);
+    assert_translation(settings.view_to_clip_matrix);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_matrices() {
+    let m = glam::Mat4::from_scale_rotation_translation(
+        glam::Vec3::new(2.0, 3.0, 4.0),
+        glam::Quat::IDENTITY,
+        glam::Vec3::from(TRANSLATION),
+    );
+    assert_translation(m.into_column_major());
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_matrices() {
+    let m = nalgebra::Matrix4::new_translation(&nalgebra::Vector3::from(TRANSLATION))
+        * nalgebra::Matrix4::new_nonuniform_scaling(&nalgebra::Vector3::new(2.0, 3.0, 4.0));
+    assert_translation(m.into_column_major());
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_matrices() {
+    let columns: [[f32; 4]; 4] =
+        std::array::from_fn(|c| std::array::from_fn(|r| COLUMN_MAJOR[c * 4 + r]));
+    assert_translation(mint::ColumnMatrix4::from(columns).into_column_major());
+
+    let rows: [[f32; 4]; 4] =
+        std::array::from_fn(|r| std::array::from_fn(|c| ROW_MAJOR[r * 4 + c]));
+    assert_translation(mint::RowMatrix4::from(rows).into_column_major());
+}
@@ -0,0 +1,68 @@
+use nrd_sys::{CommonSettings, MotionVectorConvention};
+
+const WIDTH: u32 = 1920;
+const HEIGHT: u32 = 1080;
+
+// Screen-space positions of a few points in the previous and current frame, in UV units
+const MOTION: [([f32; 2], [f32; 2]); 4] = [
+    ([0.5, 0.5], [0.5, 0.5]),
+    ([0.25, 0.75], [0.3, 0.7]),
+    ([0.9, 0.1], [0.1, 0.9]),
+    ([0.0, 1.0], [0.001, 0.998]),
+];
+
+fn encode(convention: MotionVectorConvention, prev: [f32; 2], curr: [f32; 2]) -> [f32; 3] {
+    let uv = [prev[0] - curr[0], prev[1] - curr[1]];
+    match convention {
+        MotionVectorConvention::Uv => [uv[0], uv[1], 0.0],
+        MotionVectorConvention::Pixels => [uv[0] * WIDTH as f32, uv[1] * HEIGHT as f32, 0.0],
+        MotionVectorConvention::Ndc => [uv[0] * 2.0, uv[1] * 2.0, 0.0],
+        MotionVectorConvention::NdcYUp => [uv[0] * 2.0, -uv[1] * 2.0, 0.0],
+        MotionVectorConvention::World => unreachable!(),
+    }
+}
+
+#[test]
+fn screen_space_motion_round_trips_to_uv() {
+    for convention in [
+        MotionVectorConvention::Uv,
+        MotionVectorConvention::Pixels,
+        MotionVectorConvention::Ndc,
+        MotionVectorConvention::NdcYUp,
+    ] {
+        let scale = convention.motion_vector_scale(WIDTH, HEIGHT);
+        assert!(!convention.is_world_space());
+        assert_eq!(scale[2], 0.0);
+        for (prev, curr) in MOTION {
+            let mv = encode(convention, prev, curr);
+            // NRD reprojects with "pixelUvPrev = pixelUv + IN_MV * motionVectorScale"
+            let reprojected = [curr[0] + mv[0] * scale[0], curr[1] + mv[1] * scale[1]];
+            assert!(
+                (reprojected[0] - prev[0]).abs() < 1e-5 && (reprojected[1] - prev[1]).abs() < 1e-5,
+                "{convention:?}: {curr:?} -> {reprojected:?}, expected {prev:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn world_space_motion_is_passed_through() {
+    let convention = MotionVectorConvention::World;
+    assert!(convention.is_world_space());
+    assert_eq!(
+        convention.motion_vector_scale(WIDTH, HEIGHT),
+        [1.0, 1.0, 1.0]
+    );
+}
+
+#[test]
+fn apply_writes_common_settings() {
+    let mut settings = CommonSettings::default();
+    MotionVectorConvention::Pixels.apply(&mut settings, 100, 50);
+    assert_eq!(settings.motion_vector_scale, [0.01, 0.02, 0.0]);
+    assert!(!settings.is_motion_vector_in_world_space);
+
+    MotionVectorConvention::World.apply(&mut settings, 100, 50);
+    assert_eq!(settings.motion_vector_scale, [1.0, 1.0, 1.0]);
+    assert!(settings.is_motion_vector_in_world_space);
+}
@@ -0,0 +1,386 @@
+use nrd_sys::packing::{
+    pack_normal_and_roughness, reblur_pack_directional_occlusion,
+    reblur_pack_radiance_and_norm_hit_dist, reblur_unpack_directional_occlusion,
+    relax_pack_radiance_and_hit_dist, sigma_pack_shadow, sigma_pack_shadow_translucency,
+    sigma_unpack_shadow_data, unpack_normal_and_roughness, unpack_output, unpack_output_image,
+    ShadowLight, FP16_MAX, FP16_MIN,
+};
+use nrd_sys::{Denoiser, HitDistanceParameters, NormalEncoding, ResourceType, RoughnessEncoding};
+
+const NORMAL_ENCODINGS: [NormalEncoding; 5] = [
+    NormalEncoding::Rgba8Unorm,
+    NormalEncoding::Rgba8Snorm,
+    NormalEncoding::R10G10B10A2Unorm,
+    NormalEncoding::Rgba16Unorm,
+    NormalEncoding::Rgba16Snorm,
+];
+
+const ROUGHNESS_ENCODINGS: [RoughnessEncoding; 3] = [
+    RoughnessEncoding::SqLinear,
+    RoughnessEncoding::LINEAR,
+    RoughnessEncoding::SqrtLinear,
+];
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    v.map(|c| c / length)
+}
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn normal_and_roughness_round_trip() {
+    let normals = [
+        [0.0, 0.0, 1.0],
+        [0.0, 0.0, -1.0],
+        [1.0, 0.0, 0.0],
+        [0.0, -1.0, 0.0],
+        [0.3, -0.4, 0.5],
+        [-0.7, 0.2, -0.6],
+        [-0.1, -0.1, -0.9],
+    ];
+    for normal_encoding in NORMAL_ENCODINGS {
+        for roughness_encoding in ROUGHNESS_ENCODINGS {
+            for normal in normals.map(normalize) {
+                for roughness in [0.0, 0.1, 0.5, 1.0] {
+                    let packed = pack_normal_and_roughness(
+                        normal,
+                        roughness,
+                        2.0,
+                        normal_encoding,
+                        roughness_encoding,
+                    );
+                    let (n, r, material_id) =
+                        unpack_normal_and_roughness(packed, normal_encoding, roughness_encoding);
+                    assert_close(&n, &normal);
+                    assert_close(&[r], &[roughness]);
+                    let expected_id = match normal_encoding {
+                        NormalEncoding::R10G10B10A2Unorm => 2.0,
+                        _ => 0.0,
+                    };
+                    assert_close(&[material_id], &[expected_id]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn packed_values_fit_texture_ranges() {
+    for normal_encoding in NORMAL_ENCODINGS {
+        let packed = pack_normal_and_roughness(
+            normalize([-0.7, 0.2, -0.6]),
+            0.5,
+            3.0,
+            normal_encoding,
+            RoughnessEncoding::LINEAR,
+        );
+        let min = match normal_encoding {
+            NormalEncoding::Rgba8Snorm | NormalEncoding::Rgba16Snorm => -1.0,
+            _ => 0.0,
+        };
+        for c in packed {
+            assert!((min..=1.0).contains(&c), "{normal_encoding:?}: {packed:?}");
+        }
+    }
+}
+
+#[test]
+fn normal_encodings_match_shader() {
+    let up = [0.0, 0.0, 1.0];
+    let pack =
+        |encoding| pack_normal_and_roughness(up, 0.25, 1.0, encoding, RoughnessEncoding::LINEAR);
+    assert_eq!(pack(NormalEncoding::Rgba8Unorm), [0.5, 0.5, 1.0, 0.25]);
+    assert_eq!(pack(NormalEncoding::Rgba16Snorm), [0.0, 0.0, 1.0, 0.25]);
+    assert_eq!(
+        pack(NormalEncoding::R10G10B10A2Unorm),
+        [0.5, 0.5, 0.25, 1.0 / 3.0]
+    );
+
+    // Normals are scaled to touch the unit cube
+    let packed = pack_normal_and_roughness(
+        normalize([1.0, 1.0, 0.0]),
+        0.0,
+        0.0,
+        NormalEncoding::Rgba8Snorm,
+        RoughnessEncoding::LINEAR,
+    );
+    assert_close(&packed, &[1.0, 1.0, 0.0, 0.0]);
+
+    // Lower hemisphere is octahedron-wrapped
+    let packed = pack_normal_and_roughness(
+        [0.0, 0.0, -1.0],
+        0.0,
+        0.0,
+        NormalEncoding::R10G10B10A2Unorm,
+        RoughnessEncoding::LINEAR,
+    );
+    assert_eq!(packed, [1.0, 1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn roughness_encodings_match_shader() {
+    let pack = |encoding| {
+        pack_normal_and_roughness(
+            [0.0, 0.0, 1.0],
+            0.25,
+            0.0,
+            NormalEncoding::Rgba8Unorm,
+            encoding,
+        )[3]
+    };
+    assert_eq!(pack(RoughnessEncoding::SqLinear), 0.0625);
+    assert_eq!(pack(RoughnessEncoding::LINEAR), 0.25);
+    assert_eq!(pack(RoughnessEncoding::SqrtLinear), 0.5);
+}
+
+#[test]
+fn reblur_radiance_is_stored_as_ycocg() {
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([1.0, 0.0, 0.0], 0.5, true),
+        [0.25, 0.5, -0.25, 0.5]
+    );
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([0.0, 1.0, 0.0], 0.5, true),
+        [0.5, 0.0, 0.5, 0.5]
+    );
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([2.0, 2.0, 2.0], 1.0, true),
+        [2.0, 0.0, 0.0, 1.0]
+    );
+}
+
+#[test]
+fn hit_distance_is_sanitized() {
+    // "0" marks missing data and must be preserved
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([1.0; 3], 0.0, true)[3],
+        0.0
+    );
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([1.0; 3], 0.0, true)[3],
+        0.0
+    );
+
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([1.0; 3], 1e-9, true)[3],
+        FP16_MIN
+    );
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([1.0; 3], 3.0, true)[3],
+        1.0
+    );
+    assert_eq!(
+        reblur_pack_radiance_and_norm_hit_dist([1.0; 3], 3.0, false)[3],
+        3.0
+    );
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([1.0; 3], 1e-9, true)[3],
+        FP16_MIN
+    );
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([1.0; 3], 1e6, true)[3],
+        FP16_MAX
+    );
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([1.0; 3], f32::NAN, true)[3],
+        0.0
+    );
+
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([f32::NAN, 1.0, 1.0], 2.0, true),
+        [0.0, 0.0, 0.0, 2.0]
+    );
+    assert_eq!(
+        relax_pack_radiance_and_hit_dist([-1.0, 1e6, 1.0], 2.0, true),
+        [0.0, FP16_MAX, 1.0, 2.0]
+    );
+}
+
+#[test]
+fn directional_occlusion_round_trips() {
+    let direction = normalize([1.0, -2.0, 0.5]);
+    let packed = reblur_pack_directional_occlusion(direction, 0.25, true);
+    assert_close(
+        &packed,
+        &[
+            direction[0] * 0.25,
+            direction[1] * 0.25,
+            direction[2] * 0.25,
+            0.25,
+        ],
+    );
+
+    let sg = reblur_unpack_directional_occlusion(packed);
+    assert_eq!(sg.c0, 0.25);
+    assert_eq!(sg.hit_dist, 0.25);
+    assert_close(&sg.direction(), &direction);
+
+    // Same sanitization as the hit distance of the other REBLUR inputs
+    assert_eq!(
+        reblur_pack_directional_occlusion(direction, 0.0, true),
+        [0.0; 4]
+    );
+    assert_eq!(
+        reblur_pack_directional_occlusion(direction, 3.0, true)[3],
+        1.0
+    );
+    assert_eq!(
+        reblur_pack_directional_occlusion([f32::NAN, 0.0, 1.0], 0.5, true),
+        [0.0, 0.0, 0.0, 0.5]
+    );
+}
+
+#[test]
+fn hit_distance_normalization_round_trips() {
+    let params = HitDistanceParameters::default();
+    for view_z in [0.1, 1.0, 10.0, -100.0] {
+        for roughness in [0.0, 0.3, 1.0] {
+            let scale = params.normalization(view_z, roughness);
+            for hit_dist in [0.0, 0.25 * scale, scale] {
+                let norm_hit_dist = params.normalize(view_z, roughness, hit_dist);
+                assert!((0.0..=1.0).contains(&norm_hit_dist));
+                let restored = params.denormalize(view_z, roughness, norm_hit_dist);
+                assert!((restored - hit_dist).abs() <= 1e-5 * scale);
+            }
+            assert_eq!(params.normalize(view_z, roughness, 2.0 * scale), 1.0);
+        }
+    }
+
+    // Rough surfaces use the viewZ based scale only: (A + |viewZ| * B) * 1
+    assert!((params.normalization(10.0, 1.0) - 4.0).abs() < 1e-5);
+    // Mirrors are scaled by C
+    assert!((params.normalization(10.0, 0.0) - 80.0).abs() < 1e-5);
+}
+
+#[test]
+fn hit_distance_parameters_follow_unit_scale() {
+    let meters = HitDistanceParameters::default();
+    let centimeters = HitDistanceParameters::from_units_per_meter(100.0);
+    assert_eq!(centimeters.a, 300.0);
+    let norm_hit_dist = meters.normalize(10.0, 0.5, 2.5);
+    assert!((centimeters.normalize(1000.0, 0.5, 250.0) - norm_hit_dist).abs() < 1e-6);
+}
+
+#[test]
+fn outputs_unpack_by_resource_type() {
+    let radiance = [0.25, 1.5, 3.0];
+    let packed = reblur_pack_radiance_and_norm_hit_dist(radiance, 0.5, true);
+    let unpacked = unpack_output(
+        Denoiser::ReblurDiffuse,
+        ResourceType::OUT_DIFF_RADIANCE_HITDIST,
+        packed,
+    )
+    .unwrap();
+    assert_close(&unpacked, &[0.25, 1.5, 3.0, 0.5]);
+
+    let packed = relax_pack_radiance_and_hit_dist(radiance, 12.0, true);
+    let unpacked = unpack_output(
+        Denoiser::RelaxDiffuseSpecular,
+        ResourceType::OUT_SPEC_RADIANCE_HITDIST,
+        packed,
+    );
+    assert_eq!(unpacked, Some([0.25, 1.5, 3.0, 12.0]));
+
+    let shadow = unpack_output(
+        Denoiser::SigmaShadowTranslucency,
+        ResourceType::OUT_SHADOW_TRANSLUCENCY,
+        [0.5, 1.0, 0.0, 0.25],
+    );
+    assert_eq!(shadow, Some([0.25, 1.0, 0.0, 0.0625]));
+
+    let direction = normalize([0.0, 3.0, -4.0]);
+    let occlusion = unpack_output(
+        Denoiser::ReblurDiffuseDirectionalOcclusion,
+        ResourceType::OUT_DIFF_DIRECTION_HITDIST,
+        reblur_pack_directional_occlusion(direction, 0.75, true),
+    )
+    .unwrap();
+    assert_close(&occlusion, &[0.0, 0.6, -0.8, 0.75]);
+
+    // Not an output of this denoiser
+    assert_eq!(
+        unpack_output(
+            Denoiser::SigmaShadow,
+            ResourceType::OUT_DIFF_RADIANCE_HITDIST,
+            packed
+        ),
+        None
+    );
+    assert_eq!(
+        unpack_output(
+            Denoiser::ReblurDiffuse,
+            ResourceType::OUT_SPEC_RADIANCE_HITDIST,
+            packed
+        ),
+        None
+    );
+    assert_eq!(
+        unpack_output(Denoiser::Reference, ResourceType::OUT_VALIDATION, packed),
+        None
+    );
+    // SH outputs need both textures
+    assert_eq!(
+        unpack_output(
+            Denoiser::ReblurDiffuseSh,
+            ResourceType::OUT_DIFF_SH0,
+            packed
+        ),
+        None
+    );
+    assert_eq!(
+        unpack_output_image(
+            Denoiser::ReblurDiffuseSh,
+            ResourceType::OUT_DIFF_SH1,
+            &[packed]
+        ),
+        None
+    );
+    // The validation overlay is written as is
+    assert_eq!(
+        unpack_output(Denoiser::SigmaShadow, ResourceType::OUT_VALIDATION, packed),
+        Some(packed)
+    );
+}
+
+#[test]
+fn sigma_shadow_data_round_trips() {
+    let sun = ShadowLight::Directional {
+        angular_radius: 0.5f32.to_radians(),
+    };
+    let tan = sun.tan_of_angular_radius();
+
+    let packed = sigma_pack_shadow(-12.0, 3.0, tan);
+    let (view_z, penumbra) = sigma_unpack_shadow_data(packed);
+    assert!((view_z + 12.0).abs() < 1e-5);
+    assert!((penumbra - 3.0 * tan).abs() < 1e-6);
+
+    // Misses stay "lit", back faces stay 0, and tiny penumbrae are kept non-zero
+    assert_eq!(sigma_pack_shadow(1.0, FP16_MAX, tan)[0], FP16_MAX);
+    assert_eq!(sigma_pack_shadow(1.0, 0.0, tan)[0], 0.0);
+    assert!(sigma_pack_shadow(1.0, 1e-9, tan)[0] > 0.0);
+
+    let (_, translucency) = sigma_pack_shadow_translucency(1.0, FP16_MAX, tan, [0.5, 2.0, -1.0]);
+    assert_eq!(translucency, [1.0, 0.5, 1.0, 0.0]);
+}
+
+#[test]
+fn sphere_light_angular_size() {
+    let light = ShadowLight::Sphere {
+        radius: 1.0,
+        distance: 2.0,
+    };
+    // sin(angle) = radius / distance
+    let expected = (0.5f32).asin().tan();
+    assert!((light.tan_of_angular_radius() - expected).abs() < 1e-6);
+
+    let inside = ShadowLight::Sphere {
+        radius: 1.0,
+        distance: 0.5,
+    };
+    assert_eq!(inside.tan_of_angular_radius(), FP16_MAX);
+}
@@ -0,0 +1,45 @@
+use nrd_sys::{
+    Preset, ReblurSettings, RelaxDiffuseSettings, RelaxDiffuseSpecularSettings,
+    RelaxSpecularSettings, SigmaSettings, REBLUR_MAX_HISTORY_FRAME_NUM,
+    RELAX_MAX_HISTORY_FRAME_NUM,
+};
+
+#[test]
+fn presets_stay_within_history_limits() {
+    for preset in Preset::ALL {
+        let reblur = ReblurSettings::from_preset(preset);
+        assert!(reblur.max_accumulated_frame_num <= REBLUR_MAX_HISTORY_FRAME_NUM);
+        assert!(reblur.max_fast_accumulated_frame_num <= reblur.max_accumulated_frame_num);
+        assert!(reblur.history_fix_frame_num <= reblur.max_fast_accumulated_frame_num);
+
+        let relax = RelaxDiffuseSpecularSettings::from_preset(preset);
+        assert!(relax.diffuse_max_accumulated_frame_num <= RELAX_MAX_HISTORY_FRAME_NUM);
+        assert!(relax.specular_max_accumulated_frame_num <= RELAX_MAX_HISTORY_FRAME_NUM);
+        assert!((2..=8).contains(&relax.atrous_iteration_num));
+        assert!((2..=8).contains(&RelaxDiffuseSettings::from_preset(preset).atrous_iteration_num));
+        assert!((2..=8).contains(&RelaxSpecularSettings::from_preset(preset).atrous_iteration_num));
+
+        let sigma = SigmaSettings::from_preset(preset);
+        assert!((1.0..=3.0).contains(&sigma.blur_radius_scale));
+    }
+}
+
+#[test]
+fn presets_order_by_cost() {
+    let performance = ReblurSettings::from_preset(Preset::Performance);
+    let quality = ReblurSettings::from_preset(Preset::Quality);
+    assert!(performance.enable_performance_mode);
+    assert!(!quality.enable_performance_mode);
+    assert!(performance.max_accumulated_frame_num <= quality.max_accumulated_frame_num);
+    assert!(ReblurSettings::from_preset(Preset::Reference).enable_reference_accumulation);
+
+    let performance = RelaxDiffuseSpecularSettings::from_preset(Preset::Performance);
+    let quality = RelaxDiffuseSpecularSettings::from_preset(Preset::Quality);
+    assert!(performance.atrous_iteration_num < quality.atrous_iteration_num);
+
+    assert_eq!(Preset::default(), Preset::Balanced);
+    assert_eq!(
+        ReblurSettings::from_preset(Preset::Balanced).blur_radius,
+        ReblurSettings::default().blur_radius
+    );
+}
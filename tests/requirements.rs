@@ -0,0 +1,121 @@
+use nrd_sys::{AnyDenoiserSettings, Denoiser, ReblurSettings, ResourceType, SettingsKind};
+
+const DENOISERS: [Denoiser; 21] = [
+    Denoiser::ReblurDiffuse,
+    Denoiser::ReblurDiffuseOcclusion,
+    Denoiser::ReblurDiffuseSh,
+    Denoiser::ReblurSpecular,
+    Denoiser::ReblurSpecularOcclusion,
+    Denoiser::ReblurSpecularSh,
+    Denoiser::ReblurDiffuseSpecular,
+    Denoiser::ReblurDiffuseSpecularOcclusion,
+    Denoiser::ReblurDiffuseSpecularSh,
+    Denoiser::ReblurDiffuseDirectionalOcclusion,
+    Denoiser::SigmaShadow,
+    Denoiser::SigmaShadowTranslucency,
+    Denoiser::RelaxDiffuse,
+    Denoiser::RelaxDiffuseSh,
+    Denoiser::RelaxSpecular,
+    Denoiser::RelaxSpecularSh,
+    Denoiser::RelaxDiffuseSpecular,
+    Denoiser::RelaxDiffuseSpecularSh,
+    Denoiser::Reference,
+    Denoiser::SpecularReflectionMv,
+    Denoiser::SpecularDeltaMv,
+];
+
+#[test]
+fn resources_follow_the_header() {
+    let common = [
+        ResourceType::IN_MV,
+        ResourceType::IN_NORMAL_ROUGHNESS,
+        ResourceType::IN_VIEWZ,
+    ];
+    for denoiser in DENOISERS {
+        let required = denoiser.required_inputs();
+        let uses_common = common.iter().filter(|r| required.contains(r)).count();
+        match denoiser {
+            Denoiser::Reference => assert_eq!(uses_common, 0),
+            Denoiser::SpecularDeltaMv => assert_eq!(uses_common, 1),
+            _ => assert_eq!(uses_common, 3, "{denoiser:?}"),
+        }
+
+        assert!(!denoiser.outputs().is_empty());
+        for input in denoiser.optional_inputs() {
+            assert!(!required.contains(input));
+        }
+    }
+
+    assert_eq!(
+        Denoiser::SigmaShadowTranslucency.outputs(),
+        [
+            ResourceType::OUT_SHADOW_TRANSLUCENCY,
+            ResourceType::OUT_VALIDATION
+        ]
+    );
+    assert_eq!(
+        Denoiser::RelaxDiffuseSpecular.optional_inputs(),
+        [
+            ResourceType::IN_DIFF_CONFIDENCE,
+            ResourceType::IN_SPEC_CONFIDENCE,
+            ResourceType::IN_DISOCCLUSION_THRESHOLD_MIX,
+            ResourceType::IN_BASECOLOR_METALNESS
+        ]
+    );
+}
+
+#[test]
+fn common_settings_resources_of_every_denoiser() {
+    for denoiser in DENOISERS {
+        let optional = denoiser.optional_inputs();
+        let outputs = denoiser.outputs();
+        match denoiser.settings_kind() {
+            Some(SettingsKind::Sigma) => {
+                assert!(optional.is_empty(), "{denoiser:?}");
+                assert!(
+                    outputs.contains(&ResourceType::OUT_VALIDATION),
+                    "{denoiser:?}"
+                );
+            }
+            Some(SettingsKind::Reference) | None => {
+                assert!(optional.is_empty(), "{denoiser:?}");
+                assert!(
+                    !outputs.contains(&ResourceType::OUT_VALIDATION),
+                    "{denoiser:?}"
+                );
+            }
+            // REBLUR and RELAX
+            Some(_) => {
+                for input in [
+                    ResourceType::IN_DISOCCLUSION_THRESHOLD_MIX,
+                    ResourceType::IN_BASECOLOR_METALNESS,
+                ] {
+                    assert!(optional.contains(&input), "{denoiser:?} {input:?}");
+                }
+                assert!(
+                    outputs.contains(&ResourceType::OUT_VALIDATION),
+                    "{denoiser:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn settings_kind_matches_settings() {
+    assert_eq!(
+        Denoiser::ReblurDiffuseSh.settings_kind(),
+        Some(SettingsKind::Reblur)
+    );
+    assert_eq!(
+        Denoiser::RelaxSpecularSh.settings_kind(),
+        Some(SettingsKind::RelaxSpecular)
+    );
+    assert_eq!(Denoiser::SpecularDeltaMv.settings_kind(), None);
+
+    let settings = AnyDenoiserSettings::from(ReblurSettings::default());
+    assert_eq!(
+        Denoiser::ReblurDiffuse.settings_kind(),
+        Some(settings.kind())
+    );
+}
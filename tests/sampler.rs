@@ -0,0 +1,33 @@
+use nrd_sys::{Sampler, SamplerAddressMode, SamplerDesc, SamplerFilter};
+
+#[test]
+fn sampler_descs() {
+    let desc = Sampler::LinearMirroredRepeat.desc();
+    assert_eq!(desc.min_filter, SamplerFilter::Linear);
+    assert_eq!(desc.mag_filter, SamplerFilter::Linear);
+    assert_eq!(desc.address_mode, SamplerAddressMode::MirroredRepeat);
+
+    let desc = SamplerDesc::from(Sampler::NearestClamp);
+    assert_eq!(desc.min_filter, SamplerFilter::Nearest);
+    assert_eq!(desc.address_mode, SamplerAddressMode::ClampToEdge);
+}
+
+#[cfg(feature = "ash")]
+#[test]
+fn vulkan_sampler() {
+    use ash::vk;
+    let info = Sampler::LinearClamp.desc().to_vk();
+    assert!(info.min_filter == vk::Filter::LINEAR);
+    assert!(info.address_mode_w == vk::SamplerAddressMode::CLAMP_TO_EDGE);
+    assert_eq!(info.anisotropy_enable, vk::FALSE);
+}
+
+#[cfg(feature = "wgpu-types")]
+#[test]
+fn wgpu_sampler() {
+    use wgpu_types::{AddressMode, FilterMode};
+    let desc = Sampler::NearestMirroredRepeat.desc().to_wgpu();
+    assert_eq!(desc.mag_filter, FilterMode::Nearest);
+    assert_eq!(desc.address_mode_u, AddressMode::MirrorRepeat);
+    assert_eq!(desc.anisotropy_clamp, 1);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use nrd_sys::{
+    AccumulationMode, CheckerboardMode, CommonSettings, Denoiser, HitDistanceReconstructionMode,
+    Identifier, ReblurSettings, RelaxDiffuseSpecularSettings, ResourceType,
+};
+
+#[test]
+fn missing_fields_use_defaults() {
+    let settings: ReblurSettings = serde_json::from_str(
+        r#"{
+            "max_accumulated_frame_num": 60,
+            "hit_distance_parameters": { "a": 30.0 },
+            "checkerboard_mode": "Black"
+        }"#,
+    )
+    .unwrap();
+    let default = ReblurSettings::default();
+    assert_eq!(settings.max_accumulated_frame_num, 60);
+    assert_eq!(settings.hit_distance_parameters.a, 30.0);
+    assert_eq!(
+        settings.hit_distance_parameters.b,
+        default.hit_distance_parameters.b
+    );
+    assert_eq!(settings.checkerboard_mode, CheckerboardMode::Black);
+    assert_eq!(settings.blur_radius, default.blur_radius);
+
+    let settings: RelaxDiffuseSpecularSettings = serde_json::from_str("{}").unwrap();
+    assert_eq!(settings.atrous_iteration_num, 5);
+    assert_eq!(
+        settings.hit_distance_reconstruction_mode,
+        HitDistanceReconstructionMode::Off
+    );
+}
+
+#[test]
+fn round_trip() {
+    let common = CommonSettings {
+        frame_index: 42,
+        accumulation_mode: AccumulationMode::Restart,
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&common).unwrap();
+    let back: CommonSettings = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.frame_index, 42);
+    assert_eq!(back.accumulation_mode, AccumulationMode::Restart);
+    assert_eq!(back.view_to_clip_matrix, common.view_to_clip_matrix);
+
+    assert_eq!(serde_json::to_string(&Identifier(7)).unwrap(), "7");
+    assert_eq!(
+        serde_json::to_string(&Denoiser::ReblurDiffuse).unwrap(),
+        r#""ReblurDiffuse""#
+    );
+    assert_eq!(
+        serde_json::to_string(&ResourceType::IN_MV).unwrap(),
+        r#""IN_MV""#
+    );
+}
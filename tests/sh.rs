@@ -0,0 +1,68 @@
+use nrd_sys::sh::{reblur_pack, relax_pack, resolve_diffuse_image, Sg};
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+fn luminance([r, g, b]: [f32; 3]) -> f32 {
+    r * 0.25 + g * 0.5 + b * 0.25
+}
+
+#[test]
+fn pack_round_trips() {
+    let radiance = [0.5, 2.0, 1.0];
+    let direction = [0.0, 0.6, 0.8];
+
+    let [sh0, sh1] = reblur_pack(radiance, 0.25, direction, true);
+    let sg = Sg::unpack(sh0, sh1);
+    assert_close(&sg.color(), &radiance);
+    assert_close(&sg.direction(), &direction);
+    assert_eq!(sg.hit_dist, 0.25);
+    assert_close(&sg.c1, &direction.map(|c| c * luminance(radiance)));
+    assert_eq!(sh1[3], 0.0);
+
+    let [sh0, sh1] = relax_pack(radiance, 40.0, direction, true);
+    let sg = Sg::unpack(sh0, sh1);
+    assert_close(&sg.color(), &radiance);
+    assert_close(&sg.direction(), &direction);
+    assert_eq!(sg.hit_dist, 40.0);
+}
+
+#[test]
+fn pack_sanitizes_inputs() {
+    let [sh0, sh1] = reblur_pack([f32::NAN, 1.0, 1.0], 2.0, [0.0, 0.0, 1.0], true);
+    assert_eq!(sh0, [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(sh1, [0.0; 4]);
+}
+
+#[test]
+fn uniform_signal_resolves_to_its_color() {
+    let radiance = [1.0, 0.5, 0.25];
+    // Samples from opposite directions cancel out the directional part
+    let [a0, a1] = reblur_pack(radiance, 0.5, [0.0, 0.0, 1.0], true);
+    let [b0, b1] = reblur_pack(radiance, 0.5, [0.0, 0.0, -1.0], true);
+    let average = |a: [f32; 4], b: [f32; 4]| std::array::from_fn(|i| (a[i] + b[i]) * 0.5);
+    let sg = Sg::unpack(average(a0, b0), average(a1, b1));
+
+    for normal in [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, -0.6, 0.8]] {
+        assert_close(&sg.resolve_diffuse(normal), &radiance);
+    }
+}
+
+#[test]
+fn directional_signal_favors_facing_normals() {
+    let [sh0, sh1] = relax_pack([1.0; 3], 1.0, [0.0, 0.0, 1.0], true);
+    let sg = Sg::unpack(sh0, [sh1[0] * 0.5, sh1[1] * 0.5, sh1[2] * 0.5, 0.0]);
+
+    let facing = sg.resolve_diffuse([0.0, 0.0, 1.0])[0];
+    let side = sg.resolve_diffuse([1.0, 0.0, 0.0])[0];
+    let away = sg.resolve_diffuse([0.0, 0.0, -1.0])[0];
+    assert!(facing > side && side > away, "{facing} {side} {away}");
+    assert!(away >= 0.0);
+
+    let image = resolve_diffuse_image(&[sh0, sh0], &[sh1, sh1], &[[0.0, 0.0, 1.0]; 2]);
+    assert_eq!(image.len(), 2);
+    assert_eq!(image[0], image[1]);
+}
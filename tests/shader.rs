@@ -0,0 +1,63 @@
+use nrd_sys::shader::ShaderLanguage;
+use nrd_sys::{NormalEncoding, RoughnessEncoding};
+
+const LANGUAGES: [ShaderLanguage; 3] = [
+    ShaderLanguage::Hlsl,
+    ShaderLanguage::Glsl,
+    ShaderLanguage::Wgsl,
+];
+
+// Every function mirrored in `packing` and `sh`
+const FUNCTIONS: &[&str] = &[
+    "NRD_FrontEnd_PackNormalAndRoughness",
+    "NRD_FrontEnd_UnpackNormalAndRoughness",
+    "REBLUR_FrontEnd_GetNormHitDist",
+    "REBLUR_GetHitDist",
+    "REBLUR_FrontEnd_PackRadianceAndNormHitDist",
+    "RELAX_FrontEnd_PackRadianceAndHitDist",
+    "SIGMA_FrontEnd_PackShadow",
+    "REBLUR_BackEnd_UnpackRadianceAndNormHitDist",
+    "RELAX_BackEnd_UnpackRadiance",
+    "SIGMA_BackEnd_UnpackShadow",
+    "REBLUR_FrontEnd_PackSh",
+    "RELAX_FrontEnd_PackSh",
+    "REBLUR_BackEnd_UnpackSh",
+    "RELAX_BackEnd_UnpackSh",
+    "REBLUR_FrontEnd_PackDirectionalOcclusion",
+    "REBLUR_BackEnd_UnpackDirectionalOcclusion",
+    "NRD_SG_ExtractColor",
+    "NRD_SG_ExtractDirection",
+    "NRD_SH_ResolveDiffuse",
+];
+
+#[test]
+fn every_language_provides_all_functions() {
+    for language in LANGUAGES {
+        for function in FUNCTIONS {
+            assert!(
+                language.source().contains(&format!("{function}(")),
+                "{language:?} is missing {function}"
+            );
+        }
+    }
+}
+
+#[test]
+fn defines_match_encoding_values() {
+    let hlsl = ShaderLanguage::Hlsl.defines(
+        NormalEncoding::R10G10B10A2Unorm,
+        RoughnessEncoding::SqrtLinear,
+    );
+    assert_eq!(
+        hlsl,
+        "#define NRD_NORMAL_ENCODING 2\n#define NRD_ROUGHNESS_ENCODING 2\n"
+    );
+    assert_eq!(
+        ShaderLanguage::Glsl.defines(NormalEncoding::Rgba8Unorm, RoughnessEncoding::LINEAR),
+        "#define NRD_NORMAL_ENCODING 0\n#define NRD_ROUGHNESS_ENCODING 1\n"
+    );
+    assert_eq!(
+        ShaderLanguage::Wgsl.defines(NormalEncoding::Rgba16Snorm, RoughnessEncoding::SqLinear),
+        "const NRD_NORMAL_ENCODING: u32 = 4u;\nconst NRD_ROUGHNESS_ENCODING: u32 = 0u;\n"
+    );
+}
@@ -0,0 +1,185 @@
+//! Golden snapshots of the instance description and first-frame dispatches of every supported
+//! denoiser, to catch ABI and behavior drift when the NRD binary changes.
+//!
+//! A missing or changed snapshot writes the new output next to it with a `.new` extension and
+//! fails. Run with `NRD_UPDATE_SNAPSHOTS=1` to record or accept the changes.
+//!
+//! Ignored until the goldens recorded against the pinned NRD binary are committed; run with
+//! `cargo test --test snapshots -- --ignored` to record or check them.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use nrd_sys::{
+    AnyDenoiserSettings, CommonSettings, Denoiser, DenoiserDesc, Identifier, Instance,
+    ReblurSettings, ReferenceSettings, RelaxDiffuseSettings, RelaxDiffuseSpecularSettings,
+    RelaxSpecularSettings, SettingsKind, SigmaSettings,
+};
+
+const RESOLUTIONS: [(u16, u16); 3] = [(64, 64), (1280, 720), (1921, 1081)];
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn default_settings(kind: SettingsKind) -> AnyDenoiserSettings {
+    match kind {
+        SettingsKind::Reblur => ReblurSettings::default().into(),
+        SettingsKind::RelaxDiffuse => RelaxDiffuseSettings::default().into(),
+        SettingsKind::RelaxSpecular => RelaxSpecularSettings::default().into(),
+        SettingsKind::RelaxDiffuseSpecular => RelaxDiffuseSpecularSettings::default().into(),
+        SettingsKind::Sigma => SigmaSettings::default().into(),
+        SettingsKind::Reference => ReferenceSettings::default().into(),
+    }
+}
+
+// Every input of the constant buffers is pinned: with a zero time delta NRD falls back to its
+// internal timer, and zero matrices make the derived ones degenerate.
+fn common_settings() -> CommonSettings {
+    #[rustfmt::skip]
+    let view_to_clip = [
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.7777778, 0.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+        0.0, 0.0, 0.1, 0.0,
+    ];
+    #[rustfmt::skip]
+    let world_to_view = [
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        0.0, -1.5, 4.0, 1.0,
+    ];
+    CommonSettings {
+        view_to_clip_matrix: view_to_clip,
+        view_to_clip_matrix_prev: view_to_clip,
+        world_to_view_matrix: world_to_view,
+        world_to_view_matrix_prev: world_to_view,
+        time_delta_between_frames: 16.0,
+        ..Default::default()
+    }
+}
+
+fn snapshot(denoiser: Denoiser, width: u16, height: u16) -> String {
+    let identifier = Identifier(0);
+    let mut instance = Instance::new(&[DenoiserDesc {
+        identifier,
+        denoiser,
+        render_width: width,
+        render_height: height,
+    }])
+    .unwrap();
+    if let Some(kind) = denoiser.settings_kind() {
+        default_settings(kind)
+            .apply(&mut instance, identifier)
+            .unwrap();
+    }
+    instance.set_common_settings(&common_settings()).unwrap();
+
+    let library = Instance::library_desc();
+    let mut out = String::new();
+    writeln!(
+        out,
+        "library {}.{}.{}",
+        library.version_major, library.version_minor, library.version_build
+    )
+    .unwrap();
+    writeln!(out, "denoiser {denoiser:?} {width}x{height}").unwrap();
+
+    let desc = instance.desc();
+    writeln!(out, "\n[instance]").unwrap();
+    writeln!(
+        out,
+        "constant buffer {} bytes, space {}, register {}",
+        desc.constant_buffer_max_data_size,
+        desc.constant_buffer_space_index,
+        desc.constant_buffer_register_index
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "samplers {:?}, space {}, register {}",
+        desc.samplers(),
+        desc.samplers_space_index,
+        desc.samplers_base_register_index
+    )
+    .unwrap();
+    writeln!(out, "resources space {}", desc.resources_space_index).unwrap();
+    writeln!(out, "{:?}", desc.descriptor_pool_desc).unwrap();
+    for (i, pipeline) in desc.pipelines().iter().enumerate() {
+        writeln!(
+            out,
+            "pipeline {i} {} {} constant data {} spirv {:016x}",
+            pipeline.shader_file_name().to_string_lossy(),
+            pipeline.shader_entry_point_name().to_string_lossy(),
+            pipeline.has_constant_data,
+            fnv1a(&pipeline.compute_shader_spirv)
+        )
+        .unwrap();
+        for range in pipeline.resource_ranges() {
+            writeln!(out, "    {range:?}").unwrap();
+        }
+    }
+    for (i, texture) in desc.permanent_pool().iter().enumerate() {
+        writeln!(out, "permanent {i} {texture:?}").unwrap();
+    }
+    for (i, texture) in desc.transient_pool().iter().enumerate() {
+        writeln!(out, "transient {i} {texture:?}").unwrap();
+    }
+
+    writeln!(out, "\n[dispatches]").unwrap();
+    let dispatches = instance.get_compute_dispatches(&[identifier]).unwrap();
+    for (i, dispatch) in dispatches.iter().enumerate() {
+        writeln!(
+            out,
+            "{i} {:?} pipeline {} grid {}x{} constants {} bytes {:016x}",
+            dispatch.name(),
+            dispatch.pipeline_index,
+            dispatch.grid_width,
+            dispatch.grid_height,
+            dispatch.constant_buffer().len(),
+            fnv1a(dispatch.constant_buffer())
+        )
+        .unwrap();
+        for resource in dispatch.resources() {
+            writeln!(out, "    {resource:?}").unwrap();
+        }
+    }
+    out
+}
+
+#[test]
+#[ignore = "no goldens recorded against the pinned NRD binary yet"]
+fn instance_and_dispatch_snapshots() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    std::fs::create_dir_all(&dir).unwrap();
+    let update = std::env::var_os("NRD_UPDATE_SNAPSHOTS").is_some_and(|v| v == "1");
+
+    let mut mismatches = Vec::new();
+    for &denoiser in Instance::library_desc().supported_denoisers() {
+        for (width, height) in RESOLUTIONS {
+            let actual = snapshot(denoiser, width, height);
+            let path = dir.join(format!("{denoiser:?}_{width}x{height}.snap"));
+            let new_path = path.with_extension("snap.new");
+            match std::fs::read_to_string(&path) {
+                Ok(expected) if expected == actual => {
+                    let _ = std::fs::remove_file(new_path);
+                }
+                _ if update => {
+                    let _ = std::fs::remove_file(new_path);
+                    std::fs::write(&path, &actual).unwrap();
+                }
+                _ => {
+                    std::fs::write(new_path, &actual).unwrap();
+                    mismatches.push(path);
+                }
+            }
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "snapshots missing or changed, compare with the .new files or rerun with NRD_UPDATE_SNAPSHOTS=1: {mismatches:#?}"
+    );
+}
@@ -0,0 +1,77 @@
+use nrd_sys::{
+    CheckerboardMode, CommonSettings, HitDistanceReconstructionMode, Preset, ReblurSettings,
+    ReferenceSettings, RelaxDiffuseSettings, RelaxDiffuseSpecularSettings, RelaxSpecularSettings,
+    SigmaSettings, Validate,
+};
+
+fn fields(result: Result<(), Vec<nrd_sys::Violation>>) -> Vec<String> {
+    result.unwrap_err().into_iter().map(|v| v.field).collect()
+}
+
+#[test]
+fn defaults_and_presets_are_valid() {
+    assert_eq!(CommonSettings::default().validate(), Ok(()));
+    assert_eq!(ReferenceSettings::default().validate(), Ok(()));
+    for preset in Preset::ALL {
+        assert_eq!(ReblurSettings::from_preset(preset).validate(), Ok(()));
+        assert_eq!(
+            RelaxDiffuseSpecularSettings::from_preset(preset).validate(),
+            Ok(())
+        );
+        assert_eq!(RelaxDiffuseSettings::from_preset(preset).validate(), Ok(()));
+        assert_eq!(
+            RelaxSpecularSettings::from_preset(preset).validate(),
+            Ok(())
+        );
+        assert_eq!(SigmaSettings::from_preset(preset).validate(), Ok(()));
+    }
+}
+
+#[test]
+fn reports_every_violation() {
+    let mut settings = ReblurSettings {
+        max_accumulated_frame_num: 100,
+        max_fast_accumulated_frame_num: 8,
+        blur_radius: -1.0,
+        lobe_angle_fraction: f32::NAN,
+        specular_probability_thresholds_for_mv_modification: [0.9, 0.5],
+        checkerboard_mode: CheckerboardMode::Black,
+        hit_distance_reconstruction_mode: HitDistanceReconstructionMode::Area3x3,
+        ..Default::default()
+    };
+    settings.hit_distance_parameters.c = 0.5;
+    assert_eq!(
+        fields(settings.validate()),
+        [
+            "hit_distance_parameters.c",
+            "max_accumulated_frame_num",
+            "blur_radius",
+            "lobe_angle_fraction",
+            "specular_probability_thresholds_for_mv_modification",
+            "hit_distance_reconstruction_mode",
+        ]
+    );
+
+    let settings = RelaxDiffuseSpecularSettings {
+        atrous_iteration_num: 9,
+        specular_max_fast_accumulated_frame_num: 40,
+        ..Default::default()
+    };
+    assert_eq!(
+        fields(settings.validate()),
+        [
+            "atrous_iteration_num",
+            "specular_max_fast_accumulated_frame_num"
+        ]
+    );
+
+    let settings = CommonSettings {
+        camera_jitter: [0.75, 0.0],
+        resolution_scale: [0.0, 1.0],
+        ..Default::default()
+    };
+    assert_eq!(
+        fields(settings.validate()),
+        ["camera_jitter", "resolution_scale"]
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "hot-reload")]
+
+use std::path::PathBuf;
+
+use nrd_sys::watcher::{SettingsWatcher, WatchError};
+use nrd_sys::{AnyDenoiserSettings, Identifier};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("nrd-sys-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn reloads_toml_on_change() {
+    let path = temp_path("settings.toml");
+    std::fs::write(
+        &path,
+        "[[denoisers]]\nidentifier = 3\n\n[denoisers.settings.reblur]\nmax_accumulated_frame_num = 60\n",
+    )
+    .unwrap();
+
+    let mut watcher = SettingsWatcher::new(&path).unwrap();
+    assert!(watcher.poll().unwrap());
+    assert!(!watcher.poll().unwrap());
+    let entry = &watcher.pending()[0];
+    assert_eq!(entry.identifier, Identifier(3));
+    match &entry.settings {
+        AnyDenoiserSettings::Reblur(settings) => {
+            assert_eq!(settings.max_accumulated_frame_num, 60);
+            assert_eq!(settings.blur_radius, 15.0);
+        }
+        other => panic!("unexpected settings {other:?}"),
+    }
+
+    // Broken edits are reported once and keep the last good settings
+    std::fs::write(&path, "[[denoisers]]\nidentifier = ").unwrap();
+    assert!(matches!(watcher.poll(), Err(WatchError::Parse(_))));
+    assert!(!watcher.poll().unwrap());
+    assert_eq!(watcher.pending().len(), 1);
+
+    std::fs::write(
+        &path,
+        "[[denoisers]]\nidentifier = 1\n\n[denoisers.settings.sigma]\nblur_radius_scale = 10.0\n",
+    )
+    .unwrap();
+    match watcher.poll() {
+        Err(WatchError::Invalid(invalid)) => {
+            assert_eq!(invalid[0].0, Identifier(1));
+            assert_eq!(invalid[0].1[0].field, "blur_radius_scale");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn reads_ron() {
+    let path = temp_path("settings.ron");
+    std::fs::write(
+        &path,
+        "(denoisers: [(identifier: 0, settings: relax_diffuse((atrous_iteration_num: 3)))])",
+    )
+    .unwrap();
+
+    let mut watcher = SettingsWatcher::new(&path).unwrap();
+    assert!(watcher.poll().unwrap());
+    match &watcher.pending()[0].settings {
+        AnyDenoiserSettings::RelaxDiffuse(settings) => {
+            assert_eq!(settings.atrous_iteration_num, 3)
+        }
+        other => panic!("unexpected settings {other:?}"),
+    }
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        SettingsWatcher::new("settings.json"),
+        Err(WatchError::UnknownFormat(_))
+    ));
+}